@@ -0,0 +1,90 @@
+//! Benchmarks of core elliptic curve operations
+//!
+//! Run with `cargo bench --bench curves`. To spot regressions, save a baseline before the change
+//! with `cargo bench --bench curves -- --save-baseline before` and compare against it after the
+//! change with `cargo bench --bench curves -- --baseline before`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use curv::arithmetic::*;
+use curv::elliptic::curves::*;
+
+fn bench_curve<E: Curve>(c: &mut Criterion) {
+    let mut group = c.benchmark_group(E::CURVE_NAME);
+
+    let s1 = Scalar::<E>::random();
+    let s2 = Scalar::<E>::random();
+    let p1 = Point::<E>::generator() * &s1;
+    let p2 = Point::<E>::generator() * &s2;
+    let n = BigInt::sample_below(Scalar::<E>::group_order());
+
+    group.bench_function("scalar_mul", |b| b.iter(|| black_box(&p1) * black_box(&s2)));
+    group.bench_function("generator_mul", |b| {
+        b.iter(|| Point::<E>::generator() * black_box(&s1))
+    });
+    group.bench_function("add_point", |b| b.iter(|| black_box(&p1) + black_box(&p2)));
+    group.bench_function("add_scalar", |b| b.iter(|| black_box(&s1) + black_box(&s2)));
+    group.bench_function("scalar_from_bigint", |b| {
+        b.iter(|| Scalar::<E>::from_bigint(black_box(&n)))
+    });
+    group.bench_function("random_point", |b| {
+        b.iter(|| Point::<E>::generator() * Scalar::random())
+    });
+    group.bench_function("base_point2", |b| b.iter(Point::<E>::base_point2));
+
+    for compressed in [true, false] {
+        let bytes = p1.to_bytes(compressed).to_vec();
+        group.bench_with_input(
+            BenchmarkId::new("point_to_bytes", compressed),
+            &compressed,
+            |b, &compressed| b.iter(|| black_box(&p1).to_bytes(compressed)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("point_from_bytes", compressed),
+            &bytes,
+            |b, bytes| b.iter(|| Point::<E>::from_bytes(black_box(bytes)).unwrap()),
+        );
+    }
+    let bytes = s1.to_bytes().to_vec();
+    group.bench_function("scalar_to_bytes", |b| b.iter(|| black_box(&s1).to_bytes()));
+    group.bench_function("scalar_from_bytes", |b| {
+        b.iter(|| Scalar::<E>::from_bytes(black_box(&bytes)).unwrap())
+    });
+
+    let scalars: Vec<Scalar<E>> = (0..64).map(|_| Scalar::random()).collect();
+    let points: Vec<Point<E>> = (0..64)
+        .map(|_| Point::generator() * Scalar::random())
+        .collect();
+    group.bench_with_input(
+        BenchmarkId::new("multiscalar_mul", scalars.len()),
+        &(&scalars, &points),
+        |b, (scalars, points)| b.iter(|| Point::multiscalar_mul(scalars, points)),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("naive_sum_of_products", scalars.len()),
+        &(&scalars, &points),
+        |b, (scalars, points)| {
+            b.iter(|| {
+                scalars
+                    .iter()
+                    .zip(points.iter())
+                    .map(|(s, p)| p * s)
+                    .sum::<Point<E>>()
+            })
+        },
+    );
+
+    group.finish();
+}
+
+fn curves(c: &mut Criterion) {
+    bench_curve::<Secp256k1>(c);
+    bench_curve::<Secp256r1>(c);
+    bench_curve::<Ed25519>(c);
+    bench_curve::<Ristretto>(c);
+    bench_curve::<Bls12_381_1>(c);
+    bench_curve::<Bls12_381_2>(c);
+}
+
+criterion_group!(benches, curves);
+criterion_main!(benches);
@@ -304,6 +304,48 @@ mod test {
         assert_eq!(one, BigInt::one());
     }
 
+    #[test]
+    fn mod_inv_of_one_is_one() {
+        let m = BigInt::from(97);
+        assert_eq!(BigInt::mod_inv(&BigInt::one(), &m), Some(BigInt::one()));
+    }
+
+    #[test]
+    fn mod_inv_modulo_curve_order() {
+        use crate::elliptic::curves::{Scalar, Secp256k1};
+
+        let q = Scalar::<Secp256k1>::group_order();
+        for a in [BigInt::from(2), q - 1, BigInt::sample_below(q)] {
+            if a.is_zero() {
+                continue;
+            }
+            let inv = BigInt::mod_inv(&a, q).unwrap();
+            assert!(BigInt::zero() <= inv && &inv < q);
+            assert_eq!(BigInt::mod_mul(&a, &inv, q), BigInt::one());
+        }
+    }
+
+    #[test]
+    fn mod_inv_returns_none_if_not_coprime() {
+        let m = BigInt::from(12);
+        assert_eq!(BigInt::mod_inv(&BigInt::from(8), &m), None);
+        assert_eq!(BigInt::mod_inv(&BigInt::zero(), &m), None);
+        assert_eq!(BigInt::mod_inv(&m, &m), None);
+    }
+
+    #[test]
+    fn mod_pow_known_values() {
+        let pow = |b: u32, e: u32, m: u32| {
+            BigInt::mod_pow(&BigInt::from(b), &BigInt::from(e), &BigInt::from(m))
+        };
+        assert_eq!(pow(2, 10, 1000), BigInt::from(24));
+        assert_eq!(pow(3, 200, 50), BigInt::from(1));
+        assert_eq!(pow(7, 0, 13), BigInt::one());
+        assert_eq!(pow(0, 0, 13), BigInt::one());
+        assert_eq!(pow(5, 3, 1), BigInt::zero());
+        assert_eq!(pow(5, 0, 1), BigInt::zero());
+    }
+
     #[test]
     #[should_panic]
     fn mod_pow_panics_if_exp_is_negative() {
@@ -142,7 +142,8 @@ pub trait BasicOps {
 pub trait Modulo: Sized {
     /// Calculates base^(exponent) (mod m)
     ///
-    /// Exponent must not be negative. Function will panic otherwise.
+    /// Exponent must not be negative. Function will panic otherwise. Result is always
+    /// reduced modulo `m`, so zero exponent gives `1` unless `m` is `1`.
     fn mod_pow(base: &Self, exponent: &Self, m: &Self) -> Self;
     /// Calculates a * b (mod m)
     fn mod_mul(a: &Self, b: &Self, modulus: &Self) -> Self;
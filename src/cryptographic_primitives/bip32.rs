@@ -0,0 +1,193 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! Hierarchical deterministic key derivation as specified in [BIP32]
+//!
+//! Child keys are derived from a parent key and a 32 bytes chain code. Non-hardened children
+//! (`index < 2^31`) can be derived from either the parent secret key or the parent public key,
+//! yielding matching key pairs. Hardened children (`index >= 2^31`, see [HARDENED]) can only be
+//! derived from the secret key.
+//!
+//! [BIP32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+//!
+//! ## Example
+//!
+//! ```rust
+//! use curv::cryptographic_primitives::bip32;
+//! use curv::elliptic::curves::Point;
+//!
+//! let (master_sk, master_cc) = bip32::master_key(b"some high-entropy seed").unwrap();
+//! let master_pk = Point::generator() * &master_sk;
+//!
+//! let (child_sk, child_cc) = bip32::derive_child(&master_sk, &master_cc, 5).unwrap();
+//! let (child_pk, child_cc2) = bip32::derive_child_public(&master_pk, &master_cc, 5).unwrap();
+//! assert_eq!(Point::generator() * child_sk, child_pk);
+//! assert_eq!(child_cc, child_cc2);
+//! ```
+
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha512;
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+use crate::elliptic::curves::{Point, Scalar, Secp256k1};
+
+/// Chain code accompanying every key in the hierarchy
+pub type ChainCode = [u8; 32];
+
+/// Child indices starting from `HARDENED` denote hardened derivation
+pub const HARDENED: u32 = 1 << 31;
+
+/// Derives master secret key and chain code from the seed
+pub fn master_key(seed: &[u8]) -> Result<(Scalar<Secp256k1>, ChainCode), DeriveError> {
+    let i = hmac_sha512(b"Bitcoin seed", &[seed]);
+    let master_sk = Scalar::from_bytes(&i[..32]).map_err(|_| DeriveError::InvalidChild)?;
+    if master_sk.is_zero() {
+        return Err(DeriveError::InvalidChild);
+    }
+    Ok((master_sk, chain_code_of(&i)))
+}
+
+/// Derives child secret key and chain code: `child = parent + I_L mod q`
+///
+/// Performs hardened derivation if `index >= HARDENED`. Returns [DeriveError::InvalidChild] with
+/// negligible probability, in which case BIP32 suggests proceeding with the next index.
+pub fn derive_child(
+    parent: &Scalar<Secp256k1>,
+    chain_code: &ChainCode,
+    index: u32,
+) -> Result<(Scalar<Secp256k1>, ChainCode), DeriveError> {
+    let i = if index >= HARDENED {
+        hmac_sha512(
+            chain_code,
+            &[&[0], &parent.to_bytes(), &index.to_be_bytes()],
+        )
+    } else {
+        let parent_pk = Point::generator() * parent;
+        hmac_sha512(
+            chain_code,
+            &[&parent_pk.to_bytes(true), &index.to_be_bytes()],
+        )
+    };
+    let tweak = Scalar::from_bytes(&i[..32]).map_err(|_| DeriveError::InvalidChild)?;
+    let child = tweak + parent;
+    if child.is_zero() {
+        return Err(DeriveError::InvalidChild);
+    }
+    Ok((child, chain_code_of(&i)))
+}
+
+/// Derives child public key and chain code: `child = parent + I_L·G`
+///
+/// Only non-hardened derivation is possible, returns [DeriveError::HardenedFromPublic] if
+/// `index >= HARDENED`.
+pub fn derive_child_public(
+    parent: &Point<Secp256k1>,
+    chain_code: &ChainCode,
+    index: u32,
+) -> Result<(Point<Secp256k1>, ChainCode), DeriveError> {
+    if index >= HARDENED {
+        return Err(DeriveError::HardenedFromPublic);
+    }
+    let i = hmac_sha512(chain_code, &[&parent.to_bytes(true), &index.to_be_bytes()]);
+    let tweak = Scalar::<Secp256k1>::from_bytes(&i[..32]).map_err(|_| DeriveError::InvalidChild)?;
+    let child = Point::generator() * tweak + parent;
+    if child.is_zero() {
+        return Err(DeriveError::InvalidChild);
+    }
+    Ok((child, chain_code_of(&i)))
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum DeriveError {
+    #[error("hardened child can't be derived from public key")]
+    HardenedFromPublic,
+    #[error("derived key is invalid, proceed with the next index")]
+    InvalidChild,
+}
+
+fn chain_code_of(i: &[u8]) -> ChainCode {
+    let mut chain_code = [0u8; 32];
+    chain_code.copy_from_slice(&i[32..]);
+    chain_code
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> Zeroizing<Vec<u8>> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC must take a key of any length");
+    for d in data {
+        mac.update(d);
+    }
+    Zeroizing::new(mac.finalize().into_bytes().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test vector 1 from [BIP32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#test-vector-1)
+    #[test]
+    fn bip32_test_vector_1() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let (master_sk, master_cc) = master_key(&seed).unwrap();
+        assert_eq!(
+            master_sk.to_hex(),
+            "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35"
+        );
+        assert_eq!(
+            hex::encode(master_cc),
+            "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508"
+        );
+
+        // (index, secret key, chain code)
+        let path = [
+            (
+                HARDENED,
+                "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+                "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141",
+            ),
+            (
+                1,
+                "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+                "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19",
+            ),
+            (
+                HARDENED + 2,
+                "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca",
+                "04466b9cc8e161e966409ca52986c584f07e9dc81f735db683c3ff6ec7b1503f",
+            ),
+            (
+                2,
+                "0f479245fb19a38a1954c5c7c0ebab2f9bdfd96a17563ef28a6a4b1a2a764ef4",
+                "cfb71883f01676f587d023cc53a35bc7f88f724b1f8c2892ac1275ac822a3edd",
+            ),
+            (
+                1000000000,
+                "471b76e389e528d6de6d816857e012c5455051cad6660850e58372a6c3e6e7c8",
+                "c783e67b921d2beb8f6b389cc646d7263b4145701dadd2161548a8b078e65e9e",
+            ),
+        ];
+        let (mut sk, mut cc) = (master_sk, master_cc);
+        for (index, expected_sk, expected_cc) in path.iter() {
+            let parent_pk = Point::generator() * &sk;
+            let (child_sk, child_cc) = derive_child(&sk, &cc, *index).unwrap();
+            assert_eq!(child_sk.to_hex(), *expected_sk);
+            assert_eq!(hex::encode(child_cc), *expected_cc);
+
+            let public_derivation = derive_child_public(&parent_pk, &cc, *index);
+            if *index >= HARDENED {
+                assert_eq!(public_derivation, Err(DeriveError::HardenedFromPublic));
+            } else {
+                let (child_pk, public_cc) = public_derivation.unwrap();
+                assert_eq!(child_pk, Point::generator() * &child_sk);
+                assert_eq!(public_cc, child_cc);
+            }
+
+            sk = child_sk;
+            cc = child_cc;
+        }
+    }
+}
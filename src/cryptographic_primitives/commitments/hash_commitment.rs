@@ -115,4 +115,15 @@ mod tests {
         let hash_result = BigInt::from_bytes(digest.finalize().as_ref());
         assert_eq!(&commitment, &hash_result);
     }
+
+    test_for_all_hashes!(test_commitment_changes_with_blinding_factor);
+    fn test_commitment_changes_with_blinding_factor<H: Digest + Clone>() {
+        let message = BigInt::sample(SECURITY_BITS);
+        let (commitment, blind_factor) = HashCommitment::<H>::create_commitment(&message);
+        let commitment2 = HashCommitment::<H>::create_commitment_with_user_defined_randomness(
+            &message,
+            &(blind_factor + 1),
+        );
+        assert_ne!(commitment, commitment2);
+    }
 }
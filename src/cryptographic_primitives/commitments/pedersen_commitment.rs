@@ -20,18 +20,19 @@ use crate::BigInt;
 ///
 pub struct PedersenCommitment<E: Curve>(PhantomData<E>);
 
+impl<E: Curve> PedersenCommitment<E> {
+    /// Computes `aG + bH`, where `H` is [base_point2](Point::base_point2)
+    pub fn eval(a: &Scalar<E>, b: &Scalar<E>) -> Point<E> {
+        Point::generator() * a + Point::base_point2() * b
+    }
+}
+
 impl<E: Curve> Commitment<Point<E>> for PedersenCommitment<E> {
     fn create_commitment_with_user_defined_randomness(
         message: &BigInt,
         blinding_factor: &BigInt,
     ) -> Point<E> {
-        let g = Point::generator();
-        let h = Point::base_point2();
-        let message_scalar: Scalar<E> = Scalar::from(message);
-        let blinding_scalar: Scalar<E> = Scalar::from(blinding_factor);
-        let mg = g * message_scalar;
-        let rh = h * blinding_scalar;
-        mg + rh
+        Self::eval(&Scalar::from(message), &Scalar::from(blinding_factor))
     }
 
     fn create_commitment(message: &BigInt) -> (Point<E>, BigInt) {
@@ -43,3 +44,57 @@ impl<E: Curve> Commitment<Point<E>> for PedersenCommitment<E> {
         (com, blinding_factor)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Commitment;
+    use super::PedersenCommitment;
+    use crate::arithmetic::traits::*;
+    use crate::elliptic::curves::{Curve, Point, Scalar};
+    use crate::{test_for_all_curves, BigInt};
+
+    test_for_all_curves!(test_commitment_with_same_inputs_is_deterministic);
+    fn test_commitment_with_same_inputs_is_deterministic<E: Curve>() {
+        let message = BigInt::sample(256);
+        let (commitment, blinding_factor) = PedersenCommitment::<E>::create_commitment(&message);
+        let commitment2 = PedersenCommitment::<E>::create_commitment_with_user_defined_randomness(
+            &message,
+            &blinding_factor,
+        );
+        assert_eq!(commitment, commitment2);
+
+        let expected = Point::generator() * Scalar::from(&message)
+            + Point::base_point2() * Scalar::from(&blinding_factor);
+        assert_eq!(commitment, expected);
+    }
+
+    test_for_all_curves!(test_commitment_is_additively_homomorphic);
+    fn test_commitment_is_additively_homomorphic<E: Curve>() {
+        let message1 = BigInt::sample(256);
+        let message2 = BigInt::sample(256);
+        let (commitment1, blinding_factor1) = PedersenCommitment::<E>::create_commitment(&message1);
+        let (commitment2, blinding_factor2) = PedersenCommitment::<E>::create_commitment(&message2);
+
+        let commitment_sum =
+            PedersenCommitment::<E>::create_commitment_with_user_defined_randomness(
+                &(message1 + message2),
+                &(blinding_factor1 + blinding_factor2),
+            );
+        assert_eq!(commitment1 + commitment2, commitment_sum);
+    }
+
+    test_for_all_curves!(test_eval_matches_commitment);
+    fn test_eval_matches_commitment<E: Curve>() {
+        let m = Scalar::<E>::random();
+        let r = Scalar::<E>::random();
+        let commitment = PedersenCommitment::<E>::create_commitment_with_user_defined_randomness(
+            &m.to_bigint(),
+            &r.to_bigint(),
+        );
+        assert_eq!(PedersenCommitment::eval(&m, &r), commitment);
+        assert_eq!(
+            PedersenCommitment::eval(&m, &Scalar::zero()),
+            Point::generator() * &m
+        );
+    }
+}
@@ -79,6 +79,15 @@ pub trait DigestExt {
     fn result_scalar<E: Curve>(self) -> Scalar<E>;
 
     fn digest_bigint(bytes: &[u8]) -> BigInt;
+
+    /// Hashes a list of bigints separated by domain `tag`
+    ///
+    /// Unlike chaining bigints via [chain_bigint](Self::chain_bigint), the tag and every input are
+    /// prefixed with their length, so different groupings of the same bytes (e.g. `[0x01, 0x0203]`
+    /// and `[0x0102, 0x03]`) result into different hashes. Consider using
+    /// [Transcript](crate::cryptographic_primitives::transcript::Transcript) if you need to hash
+    /// points and scalars as well.
+    fn digest_bigints_with_domain(tag: &[u8], inputs: &[&BigInt]) -> BigInt;
 }
 
 impl<D> DigestExt for D
@@ -124,6 +133,18 @@ where
     fn digest_bigint(bytes: &[u8]) -> BigInt {
         Self::new().chain(bytes).result_bigint()
     }
+
+    fn digest_bigints_with_domain(tag: &[u8], inputs: &[&BigInt]) -> BigInt {
+        let mut hasher = Self::new();
+        hasher.update((tag.len() as u64).to_le_bytes());
+        hasher.update(tag);
+        for n in inputs {
+            let bytes = n.to_bytes();
+            hasher.update((bytes.len() as u64).to_le_bytes());
+            hasher.update(&bytes);
+        }
+        hasher.result_bigint()
+    }
 }
 
 /// [Hmac] extension allowing to use bigints to instantiate hmac, update, and finalize it.
@@ -271,6 +292,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn digest_bigint_sha256_test() {
+        let result = Sha256::digest_bigint(b"abc");
+        assert_eq!(
+            result.to_hex(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+
+        // Leading zeros of the input are hashed as-is
+        let result = Sha256::digest_bigint(&[0, 0, 1]);
+        assert_eq!(
+            result.to_hex(),
+            "cf7605ed1bc735f6c825554154627467e1cac9df54cee8699218ed434603c568"
+        );
+        assert_ne!(
+            result,
+            Sha256::new().chain_bigint(&BigInt::one()).result_bigint()
+        );
+    }
+
     crate::test_for_all_curves_and_hashes!(create_hash_from_ge_test);
     fn create_hash_from_ge_test<E: Curve, H: Digest + Clone>() {
         let generator = Point::<E>::generator();
@@ -292,6 +333,39 @@ mod test {
         assert_eq!(result2, result3);
     }
 
+    crate::test_for_all_hashes!(chunked_updates_match_single_shot_hash);
+    fn chunked_updates_match_single_shot_hash<H: Digest + Clone>() {
+        let message = (0..10_000u32).map(|i| i as u8).collect::<Vec<_>>();
+        let expected = H::digest_bigint(&message);
+
+        for chunk_size in [1, 7, 64, 1000] {
+            let mut hasher = H::new();
+            for chunk in message.chunks(chunk_size) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.result_bigint(), expected);
+        }
+    }
+
+    crate::test_for_all_hashes!(digest_bigints_with_domain_test);
+    fn digest_bigints_with_domain_test<H: Digest + Clone>() {
+        let (a, b) = (BigInt::from(0x01), BigInt::from(0x0203));
+        let (c, d) = (BigInt::from(0x0102), BigInt::from(0x03));
+        // Plain concatenation can't distinguish these groupings
+        assert_eq!(
+            H::new().chain_bigint(&a).chain_bigint(&b).result_bigint(),
+            H::new().chain_bigint(&c).chain_bigint(&d).result_bigint()
+        );
+
+        let result1 = H::digest_bigints_with_domain(b"tag", &[&a, &b]);
+        assert_ne!(result1, H::digest_bigints_with_domain(b"tag", &[&c, &d]));
+        assert_ne!(
+            result1,
+            H::digest_bigints_with_domain(b"other tag", &[&a, &b])
+        );
+        assert_eq!(result1, H::digest_bigints_with_domain(b"tag", &[&a, &b]));
+    }
+
     crate::test_for_all_hashes!(create_hmac_test);
     fn create_hmac_test<H>()
     where
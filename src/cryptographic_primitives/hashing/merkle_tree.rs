@@ -5,6 +5,7 @@
     License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
 */
 
+use std::convert::TryFrom;
 use std::marker::PhantomData;
 
 use digest::{Digest, Output};
@@ -38,6 +39,15 @@ impl<E: Curve, H: Digest + Clone> MT256<E, H> {
             .zip(&self.leaves)
             .find(|(_, leaf)| **leaf == point)
             .map(|(i, _)| i)?;
+        self.build_proof_at(index)
+    }
+
+    /// Builds inclusion proof of a leaf at given position
+    ///
+    /// Unlike [build_proof](Self::build_proof), it's unambiguous if the same point occurs in the
+    /// tree several times. Returns `None` if index is out of bounds.
+    pub fn build_proof_at(&self, index: u32) -> Option<Proof<E, H>> {
+        let point = self.leaves.get(usize::try_from(index).ok()?)?.clone();
         let proof = self.tree.build_proof(&[index])?;
         Some(Proof {
             index: proof.indices()[0],
@@ -92,7 +102,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::MT256;
-    use crate::elliptic::curves::{Curve, Point};
+    use crate::elliptic::curves::{Curve, Point, Scalar};
 
     use crate::test_for_all_curves;
 
@@ -123,4 +133,24 @@ mod tests {
         let root = mt256.get_root();
         proof1.verify(&root).expect("proof is invalid");
     }
+
+    test_for_all_curves!(test_mt_functionality_eight_leaves);
+
+    fn test_mt_functionality_eight_leaves<E: Curve>() {
+        let leaves = (1..=8)
+            .map(|i| Point::<E>::generator() * Scalar::from(i))
+            .collect::<Vec<_>>();
+        let mt256 = MT256::<_, sha2::Sha256>::create_tree(leaves.clone());
+        let root = mt256.get_root();
+        for (i, leaf) in (0u32..).zip(&leaves) {
+            let proof = mt256.build_proof_at(i).unwrap();
+            assert_eq!(&proof.point, leaf);
+            proof.verify(&root).expect("proof is invalid");
+        }
+        assert!(mt256.build_proof_at(8).is_none());
+
+        let mut forged = mt256.build_proof_at(3).unwrap();
+        forged.point = leaves[4].clone();
+        assert!(forged.verify(&root).is_err());
+    }
 }
@@ -6,6 +6,7 @@
 */
 mod ext;
 pub mod merkle_tree;
+pub mod rfc6979;
 
 pub use digest::Digest;
 pub use ext::*;
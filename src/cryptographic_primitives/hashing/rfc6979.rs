@@ -0,0 +1,184 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+//! Deterministic nonce generation as specified in [RFC6979](https://tools.ietf.org/html/rfc6979)
+//!
+//! Nonces are derived from the secret key and the message hash using HMAC-SHA256, so signing the
+//! same message twice results into the same nonce, and no randomness source is needed at signing
+//! time. Used by [ECDSA](crate::cryptographic_primitives::signatures::ecdsa), but can be used by
+//! any other scheme that needs a secret nonce bound to the secret key and the message.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use curv::arithmetic::*;
+//! use curv::cryptographic_primitives::hashing::{rfc6979, Digest, DigestExt};
+//! use curv::elliptic::curves::{Scalar, Secp256k1};
+//! use sha2::Sha256;
+//!
+//! let secret_key = Scalar::<Secp256k1>::random();
+//! let message_hash = Sha256::new().chain(b"hello world").result_bigint();
+//!
+//! let k = rfc6979::generate_k(&secret_key, &message_hash);
+//! assert!(!k.is_zero());
+//! assert_eq!(k, rfc6979::generate_k(&secret_key, &message_hash));
+//! ```
+
+use std::marker::PhantomData;
+
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use crate::arithmetic::*;
+use crate::elliptic::curves::{Curve, Scalar};
+
+/// Generates deterministic nonce `k ∈ [1, q)` as specified in RFC6979 section 3.2 (with HMAC-SHA256)
+///
+/// `message_hash` is a hash of the message converted to integer (`bits2int` in RFC terms), i.e.
+/// its bit length shouldn't exceed bit length of group order `q`. It's reduced modulo `q`.
+///
+/// Returns the first nonce produced by [NonceGenerator].
+pub fn generate_k<E: Curve>(secret_key: &Scalar<E>, message_hash: &BigInt) -> Scalar<E> {
+    NonceGenerator::new(secret_key, message_hash)
+        .next()
+        .expect("nonce generator never ends")
+}
+
+/// Infinite sequence of deterministic nonces `k ∈ [1, q)` (RFC6979 section 3.2, with HMAC-SHA256)
+///
+/// The first nonce is the one returned by [generate_k]. If a signature scheme can't use the
+/// nonce (e.g. ECDSA obtained `r = 0` or `s = 0`), RFC6979 prescribes to keep generating nonces
+/// by continuing HMAC_DRBG (step h.3), which is what every following call to `next()` does.
+pub struct NonceGenerator<E: Curve> {
+    k: Zeroizing<Vec<u8>>,
+    v: Zeroizing<Vec<u8>>,
+    first: bool,
+    _curve: PhantomData<E>,
+}
+
+impl<E: Curve> NonceGenerator<E> {
+    /// Instantiates HMAC_DRBG with the secret key and the message hash
+    ///
+    /// See [generate_k] regarding `message_hash` requirements.
+    pub fn new(secret_key: &Scalar<E>, message_hash: &BigInt) -> Self {
+        let q = Scalar::<E>::group_order();
+        let rolen = (q.bit_length() + 7) / 8;
+
+        let int2octets = |x: &BigInt| {
+            let bytes = Zeroizing::new(x.to_bytes());
+            let mut octets = Zeroizing::new(vec![0u8; rolen]);
+            octets[rolen - bytes.len()..].copy_from_slice(&bytes);
+            octets
+        };
+        let x = int2octets(&secret_key.to_bigint());
+        // bits2octets(h1) = int2octets(bits2int(h1) mod q)
+        let h = int2octets(&message_hash.modulus(q));
+
+        let mut v = Zeroizing::new(vec![0x01u8; 32]);
+        let mut k = Zeroizing::new(vec![0x00u8; 32]);
+        k = hmac_sha256(&k, &[&v, &[0x00], &x, &h]);
+        v = hmac_sha256(&k, &[&v]);
+        k = hmac_sha256(&k, &[&v, &[0x01], &x, &h]);
+        v = hmac_sha256(&k, &[&v]);
+
+        Self {
+            k,
+            v,
+            first: true,
+            _curve: PhantomData,
+        }
+    }
+}
+
+impl<E: Curve> Iterator for NonceGenerator<E> {
+    type Item = Scalar<E>;
+
+    fn next(&mut self) -> Option<Scalar<E>> {
+        let q = Scalar::<E>::group_order();
+        let qlen = q.bit_length();
+        let rolen = (qlen + 7) / 8;
+
+        loop {
+            if !self.first {
+                self.k = hmac_sha256(&self.k, &[&self.v, &[0x00]]);
+                self.v = hmac_sha256(&self.k, &[&self.v]);
+            }
+            self.first = false;
+
+            let mut t = Zeroizing::new(Vec::with_capacity(rolen + 32));
+            while t.len() * 8 < qlen {
+                self.v = hmac_sha256(&self.k, &[&self.v]);
+                t.extend_from_slice(&self.v);
+            }
+            // bits2int(t)
+            let nonce = Zeroizing::new(BigInt::from_bytes(&t) >> (t.len() * 8 - qlen));
+            if !nonce.is_zero() && &*nonce < q {
+                return Some(Scalar::from_bigint(&nonce));
+            }
+        }
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[&[u8]]) -> Zeroizing<Vec<u8>> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC must take a key of any length");
+    for d in data {
+        mac.update(d);
+    }
+    Zeroizing::new(mac.finalize().into_bytes().to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+    use crate::elliptic::curves::Secp256r1;
+
+    /// RFC6979 A.2.5: ECDSA, 256 bits (prime field), with SHA-256
+    #[test]
+    fn p256_sha256_test_vectors() {
+        let secret_key = Scalar::<Secp256r1>::from_hex(
+            "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721",
+        )
+        .unwrap();
+        // (message, k)
+        let vectors = [
+            (
+                "sample",
+                "a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60",
+            ),
+            (
+                "test",
+                "d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0",
+            ),
+        ];
+        for (message, k) in vectors.iter() {
+            let message_hash = BigInt::from_bytes(&Sha256::digest(message.as_bytes()));
+            assert_eq!(generate_k(&secret_key, &message_hash).to_hex(), *k);
+        }
+    }
+
+    #[test]
+    fn nonce_generator_continues_drbg() {
+        let secret_key = Scalar::<Secp256r1>::random();
+        let message_hash = BigInt::from_bytes(&Sha256::digest(b"message"));
+
+        let nonces = NonceGenerator::new(&secret_key, &message_hash)
+            .take(3)
+            .collect::<Vec<_>>();
+        assert_eq!(nonces[0], generate_k(&secret_key, &message_hash));
+        assert_ne!(nonces[0], nonces[1]);
+        assert_ne!(nonces[1], nonces[2]);
+        assert_eq!(
+            nonces,
+            NonceGenerator::new(&secret_key, &message_hash)
+                .take(3)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -5,8 +5,11 @@
     License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
 */
 
+pub mod bip32;
 pub mod commitments;
 pub mod hashing;
 pub mod proofs;
 pub mod secret_sharing;
+pub mod signatures;
+pub mod transcript;
 pub mod twoparty;
@@ -0,0 +1,161 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::cryptographic_primitives::commitments::pedersen_commitment::PedersenCommitment;
+use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
+use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::marker::HashChoice;
+
+use super::ProofError;
+
+/// Proof that Pedersen commitment `C = bG + rH` commits to a bit `b ∈ {0, 1}`
+///
+/// `H` is [base_point2](Point::base_point2), commitment can be computed via
+/// [PedersenCommitment::eval]. The proof is an OR-composition (R. Cramer, I. Damgård,
+/// B. Schoenmakers. Proofs of partial knowledge and simplified design of witness hiding protocols.
+/// In CRYPTO ’94) of two Schnorr proofs of knowledge of `r` such that:
+/// `Y_0 = C = rH` (if `b = 0`) or `Y_1 = C - G = rH` (if `b = 1`).
+///
+/// The protocol:
+/// 1: For the true branch `b`, prover chooses random `k` and computes `A_b = kH`. For the other
+///    branch, prover simulates the proof: chooses random `e_{1-b}`, `z_{1-b}` and computes
+///    `A_{1-b} = z_{1-b}H - e_{1-b}Y_{1-b}`
+/// prover calculates challenge e = H(G, H, C, A_0, A_1)
+/// prover calculates e_b = e - e_{1-b}, z_b = k + e_b r
+/// prover sends pi = {A_0, A_1, e_0, e_1, z_0, z_1}
+///
+/// verifier checks that e_0 + e_1 = H(G, H, C, A_0, A_1), z_0 H = A_0 + e_0 Y_0 and
+/// z_1 H = A_1 + e_1 Y_1
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct BitProof<E: Curve, H: Digest + Clone> {
+    pub a0: Point<E>,
+    pub a1: Point<E>,
+    pub e0: Scalar<E>,
+    pub e1: Scalar<E>,
+    pub z0: Scalar<E>,
+    pub z1: Scalar<E>,
+    #[serde(skip)]
+    pub hash_choice: HashChoice<H>,
+}
+
+impl<E: Curve, H: Digest + Clone> BitProof<E, H> {
+    /// Proves that commitment `bit·G + blinding·H` commits to a bit
+    pub fn prove(bit: bool, blinding: &Scalar<E>) -> BitProof<E, H> {
+        let h = Point::<E>::base_point2();
+        let commitment = PedersenCommitment::eval(&Scalar::from(u16::from(bit)), blinding);
+        let (y0, y1) = Self::branches(&commitment);
+
+        let k = Scalar::random();
+        let e_simulated = Scalar::random();
+        let z_simulated = Scalar::random();
+        let a_real = h * &k;
+        let (a0, a1) = if bit {
+            (h * &z_simulated - &y0 * &e_simulated, a_real)
+        } else {
+            (a_real, h * &z_simulated - &y1 * &e_simulated)
+        };
+
+        let e = Self::challenge(&commitment, &a0, &a1);
+        let e_real = e - &e_simulated;
+        let z_real = k + &e_real * blinding;
+        let ((e0, z0), (e1, z1)) = if bit {
+            ((e_simulated, z_simulated), (e_real, z_real))
+        } else {
+            ((e_real, z_real), (e_simulated, z_simulated))
+        };
+
+        BitProof {
+            a0,
+            a1,
+            e0,
+            e1,
+            z0,
+            z1,
+            hash_choice: HashChoice::new(),
+        }
+    }
+
+    /// Verifies that `commitment` commits to a bit
+    pub fn verify(&self, commitment: &Point<E>) -> Result<(), ProofError> {
+        let h = Point::<E>::base_point2();
+        let (y0, y1) = Self::branches(commitment);
+
+        let e = Self::challenge(commitment, &self.a0, &self.a1);
+        if e != &self.e0 + &self.e1 {
+            return Err(ProofError);
+        }
+        if h * &self.z0 == &self.a0 + y0 * &self.e0 && h * &self.z1 == &self.a1 + y1 * &self.e1 {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
+
+    /// Returns `(C, C - G)`
+    fn branches(commitment: &Point<E>) -> (Point<E>, Point<E>) {
+        (commitment.clone(), commitment - Point::generator())
+    }
+
+    fn challenge(commitment: &Point<E>, a0: &Point<E>, a1: &Point<E>) -> Scalar<E> {
+        H::new()
+            .chain_point(&Point::<E>::generator().to_point())
+            .chain_point(Point::<E>::base_point2())
+            .chain_point(commitment)
+            .chain_point(a0)
+            .chain_point(a1)
+            .result_scalar()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::test_for_all_curves_and_hashes!(test_bit_proof);
+    fn test_bit_proof<E: Curve, H: Digest + Clone>() {
+        for bit in [false, true] {
+            let blinding = Scalar::random();
+            let commitment = PedersenCommitment::eval(&Scalar::from(u16::from(bit)), &blinding);
+            let proof = BitProof::<E, H>::prove(bit, &blinding);
+            assert!(proof.verify(&commitment).is_ok());
+
+            // proof is bound to the commitment
+            let other = PedersenCommitment::eval(&Scalar::from(u16::from(!bit)), &blinding);
+            assert!(proof.verify(&other).is_err());
+        }
+    }
+
+    crate::test_for_all_curves_and_hashes!(test_bit_proof_rejects_commitment_to_two);
+    fn test_bit_proof_rejects_commitment_to_two<E: Curve, H: Digest + Clone>() {
+        let blinding = Scalar::random();
+        let commitment = PedersenCommitment::eval(&Scalar::from(2), &blinding);
+        for bit in [false, true] {
+            let proof = BitProof::<E, H>::prove(bit, &blinding);
+            assert!(proof.verify(&commitment).is_err());
+        }
+    }
+
+    crate::test_for_all_curves_and_hashes!(test_bad_bit_proof);
+    fn test_bad_bit_proof<E: Curve, H: Digest + Clone>() {
+        let blinding = Scalar::random();
+        let commitment = PedersenCommitment::eval(&Scalar::from(1), &blinding);
+        let proof = BitProof::<E, H>::prove(true, &blinding);
+
+        let mut tampered = proof.clone();
+        tampered.z0 = &tampered.z0 + Scalar::from(1);
+        assert!(tampered.verify(&commitment).is_err());
+
+        // shifting challenge between the branches breaks the proof
+        let mut tampered = proof;
+        tampered.e0 = &tampered.e0 + Scalar::from(1);
+        tampered.e1 = &tampered.e1 - Scalar::from(1);
+        assert!(tampered.verify(&commitment).is_err());
+    }
+}
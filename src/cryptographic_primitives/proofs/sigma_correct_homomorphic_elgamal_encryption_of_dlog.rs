@@ -123,7 +123,6 @@ mod tests {
         assert!(proof.verify(&delta).is_ok());
     }
 
-    // TODO: add more fail scenarios
     test_for_all_curves_and_hashes!(test_wrong_homo_elgamal);
     fn test_wrong_homo_elgamal<E: Curve, H: Digest + Clone>() {
         // test for Q = (x+1)G
@@ -146,4 +145,80 @@ mod tests {
         let proof = HomoELGamalDlogProof::<E, H>::prove(&witness, &delta);
         assert!(!proof.verify(&delta).is_ok());
     }
+
+    fn honest_statement<E: Curve>(
+        witness: &HomoElGamalDlogWitness<E>,
+    ) -> HomoElGamalDlogStatement<E> {
+        let G = Point::<E>::generator();
+        let Y = G * Scalar::random();
+        HomoElGamalDlogStatement {
+            G: G.to_point(),
+            D: G * &witness.x + &Y * &witness.r,
+            E: G * &witness.r,
+            Q: G * &witness.x,
+            Y,
+        }
+    }
+
+    test_for_all_curves_and_hashes!(test_homo_elgamal_wrong_dlog);
+    fn test_homo_elgamal_wrong_dlog<E: Curve, H: Digest + Clone>() {
+        // ciphertext encrypts x, but Q = (x+1)G
+        let witness = HomoElGamalDlogWitness {
+            r: Scalar::random(),
+            x: Scalar::random(),
+        };
+        let mut delta = honest_statement(&witness);
+        delta.Q = &delta.Q + Point::generator();
+        let proof = HomoELGamalDlogProof::<E, H>::prove(&witness, &delta);
+        assert!(proof.verify(&delta).is_err());
+    }
+
+    test_for_all_curves_and_hashes!(test_homo_elgamal_wrong_plaintext);
+    fn test_homo_elgamal_wrong_plaintext<E: Curve, H: Digest + Clone>() {
+        // Q = xG, but ciphertext encrypts x+1
+        let witness = HomoElGamalDlogWitness {
+            r: Scalar::random(),
+            x: Scalar::random(),
+        };
+        let mut delta = honest_statement(&witness);
+        delta.D = &delta.D + Point::generator();
+        let proof = HomoELGamalDlogProof::<E, H>::prove(&witness, &delta);
+        assert!(proof.verify(&delta).is_err());
+    }
+
+    test_for_all_curves_and_hashes!(test_homo_elgamal_wrong_public_key);
+    fn test_homo_elgamal_wrong_public_key<E: Curve, H: Digest + Clone>() {
+        let witness = HomoElGamalDlogWitness {
+            r: Scalar::random(),
+            x: Scalar::random(),
+        };
+        let delta = honest_statement(&witness);
+        let proof = HomoELGamalDlogProof::<E, H>::prove(&witness, &delta);
+
+        let mut other = delta;
+        other.Y = Point::generator() * Scalar::random();
+        assert!(proof.verify(&other).is_err());
+    }
+
+    test_for_all_curves_and_hashes!(test_homo_elgamal_tampered_proof);
+    fn test_homo_elgamal_tampered_proof<E: Curve, H: Digest + Clone>() {
+        let witness = HomoElGamalDlogWitness {
+            r: Scalar::random(),
+            x: Scalar::random(),
+        };
+        let delta = honest_statement(&witness);
+        let proof = HomoELGamalDlogProof::<E, H>::prove(&witness, &delta);
+
+        let mut tampered = proof.clone();
+        tampered.z1 = &tampered.z1 + Scalar::from(1);
+        assert!(tampered.verify(&delta).is_err());
+
+        let mut tampered = proof.clone();
+        tampered.z2 = &tampered.z2 + Scalar::from(1);
+        assert!(tampered.verify(&delta).is_err());
+
+        let mut tampered = proof;
+        tampered.A3 = &tampered.A3 + Point::generator();
+        assert!(tampered.verify(&delta).is_err());
+    }
 }
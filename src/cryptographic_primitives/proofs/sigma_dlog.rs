@@ -77,6 +77,42 @@ impl<E: Curve, H: Digest + Clone> DLogProof<E, H> {
             Err(ProofError)
         }
     }
+
+    /// Verifies many proofs at once
+    ///
+    /// Instead of checking `s_i G + c_i PK_i = R_i` for every proof separately, checks a random
+    /// linear combination of these equations `sum w_i (s_i G + c_i PK_i - R_i) = O` using a single
+    /// [multiscalar multiplication](Point::multiscalar_mul), which is considerably faster. Returns
+    /// error if any of the proofs is invalid (except with negligible probability), but doesn't
+    /// tell which one.
+    pub fn batch_verify(proofs: &[DLogProof<E, H>]) -> Result<(), ProofError> {
+        let generator = Point::<E>::generator();
+        let weights = Scalar::<E>::random_vec(proofs.len());
+
+        let mut scalars = Vec::with_capacity(2 * proofs.len() + 1);
+        let mut points = Vec::with_capacity(2 * proofs.len() + 1);
+        let mut generator_scalar = Scalar::zero();
+        for (proof, w) in proofs.iter().zip(&weights) {
+            let challenge: Scalar<E> = H::new()
+                .chain_point(&proof.pk_t_rand_commitment)
+                .chain_point(&generator.to_point())
+                .chain_point(&proof.pk)
+                .result_scalar();
+            generator_scalar = generator_scalar + w * &proof.challenge_response;
+            scalars.push(w * challenge);
+            points.push(proof.pk.clone());
+            scalars.push(-w);
+            points.push(proof.pk_t_rand_commitment.clone());
+        }
+        scalars.push(generator_scalar);
+        points.push(generator.to_point());
+
+        if Point::multiscalar_mul(&scalars, &points).is_zero() {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -89,4 +125,48 @@ mod tests {
         let dlog_proof = DLogProof::<E, H>::prove(&witness);
         assert!(DLogProof::verify(&dlog_proof).is_ok());
     }
+
+    crate::test_for_all_curves_and_hashes!(test_bad_dlog_proof);
+    fn test_bad_dlog_proof<E: Curve, H: Digest + Clone>() {
+        let witness = Scalar::random();
+        let dlog_proof = DLogProof::<E, H>::prove(&witness);
+
+        let mut tampered_response = dlog_proof.clone();
+        tampered_response.challenge_response =
+            &tampered_response.challenge_response + Scalar::from(1);
+        assert!(DLogProof::verify(&tampered_response).is_err());
+
+        let mut tampered_pk = dlog_proof;
+        tampered_pk.pk = &tampered_pk.pk + Point::generator();
+        assert!(DLogProof::verify(&tampered_pk).is_err());
+    }
+
+    crate::test_for_all_curves_and_hashes!(test_batch_verify_dlog_proofs);
+    fn test_batch_verify_dlog_proofs<E: Curve, H: Digest + Clone>() {
+        let mut proofs = (0..10)
+            .map(|_| DLogProof::<E, H>::prove(&Scalar::random()))
+            .collect::<Vec<_>>();
+        assert!(DLogProof::batch_verify(&proofs).is_ok());
+        assert!(DLogProof::<E, H>::batch_verify(&[]).is_ok());
+
+        proofs[7].challenge_response = &proofs[7].challenge_response + Scalar::from(1);
+        assert!(DLogProof::batch_verify(&proofs).is_err());
+    }
+
+    crate::test_for_all_curves_and_hashes!(test_dlog_proof_serde_round_trip);
+    fn test_dlog_proof_serde_round_trip<E: Curve, H: Digest + Clone>() {
+        let dlog_proof = DLogProof::<E, H>::prove(&Scalar::random());
+        let serialized = serde_json::to_string(&dlog_proof).unwrap();
+        let deserialized: DLogProof<E, H> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.pk, dlog_proof.pk);
+        assert_eq!(
+            deserialized.pk_t_rand_commitment,
+            dlog_proof.pk_t_rand_commitment
+        );
+        assert_eq!(
+            deserialized.challenge_response,
+            dlog_proof.challenge_response
+        );
+        assert!(DLogProof::verify(&deserialized).is_ok());
+    }
 }
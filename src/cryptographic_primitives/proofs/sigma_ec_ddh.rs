@@ -133,4 +133,24 @@ mod tests {
         let proof = ECDDHProof::<E, H>::prove(&w, &delta);
         assert!(!proof.verify(&delta).is_ok());
     }
+
+    test_for_all_curves_and_hashes!(test_ecddh_proof_serde_round_trip);
+    fn test_ecddh_proof_serde_round_trip<E: Curve, H: Digest + Clone>() {
+        let x = Scalar::random();
+        let g2 = Point::base_point2();
+        let delta = ECDDHStatement {
+            g1: Point::generator().to_point(),
+            g2: g2.clone(),
+            h1: Point::generator() * &x,
+            h2: g2 * &x,
+        };
+        let proof = ECDDHProof::<E, H>::prove(&ECDDHWitness { x }, &delta);
+
+        let serialized = serde_json::to_string(&proof).unwrap();
+        let deserialized: ECDDHProof<E, H> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.a1, proof.a1);
+        assert_eq!(deserialized.a2, proof.a2);
+        assert_eq!(deserialized.z, proof.z);
+        assert!(deserialized.verify(&delta).is_ok());
+    }
 }
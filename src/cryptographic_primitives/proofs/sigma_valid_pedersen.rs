@@ -8,7 +8,6 @@
 use serde::{Deserialize, Serialize};
 
 use crate::cryptographic_primitives::commitments::pedersen_commitment::PedersenCommitment;
-use crate::cryptographic_primitives::commitments::traits::Commitment;
 use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
 use crate::elliptic::curves::{Curve, Point, Scalar};
 use crate::marker::HashChoice;
@@ -46,10 +45,7 @@ impl<E: Curve, H: Digest + Clone> PedersenProof<E, H> {
         let s2 = Scalar::random();
         let a1 = g * &s1;
         let a2 = h * &s2;
-        let com: Point<E> = PedersenCommitment::create_commitment_with_user_defined_randomness(
-            &m.to_bigint(),
-            &r.to_bigint(),
-        );
+        let com = PedersenCommitment::eval(m, r);
 
         let e = H::new()
             .chain_points([&g.to_point(), h, &com, &a1, &a2])
@@ -79,9 +75,7 @@ impl<E: Curve, H: Digest + Clone> PedersenProof<E, H> {
             .chain_points([&g.to_point(), h, &proof.com, &proof.a1, &proof.a2])
             .result_scalar();
 
-        let z1g = g * &proof.z1;
-        let z2h = h * &proof.z2;
-        let lhs = &z1g + &z2h;
+        let lhs = PedersenCommitment::eval(&proof.z1, &proof.z2);
         let rhs = &proof.a1 + &proof.a2;
         let ecom = &proof.com * &e;
         let rhs = rhs + &ecom;
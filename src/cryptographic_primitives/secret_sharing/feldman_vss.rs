@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::cryptographic_primitives::secret_sharing::Polynomial;
 use crate::elliptic::curves::{Curve, Point, Scalar};
-use crate::ErrorSS::{self, VerifyShareError};
+use crate::ErrorSS::{self, CommitmentsLengthMismatch, VerifyShareError};
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct ShamirSecretSharing {
@@ -60,12 +60,7 @@ impl<E: Curve> VerifiableSS<E> {
         let polynomial = Polynomial::<E>::sample_exact_with_fixed_const_term(t, secret.clone());
         let shares = polynomial.evaluate_many_bigint(1..=n).collect();
 
-        let g = Point::<E>::generator();
-        let commitments = polynomial
-            .coefficients()
-            .iter()
-            .map(|coef| g * coef)
-            .collect::<Vec<_>>();
+        let commitments = polynomial.commit();
         (
             VerifiableSS {
                 parameters: ShamirSecretSharing {
@@ -123,12 +118,7 @@ impl<E: Curve> VerifiableSS<E> {
             .evaluate_many_bigint(indicies.map(NonZeroU16::get))
             .collect();
 
-        let g = Point::<E>::generator();
-        let commitments = polynomial
-            .coefficients()
-            .iter()
-            .map(|coef| g * coef)
-            .collect::<Vec<Point<E>>>();
+        let commitments = polynomial.commit();
         (
             VerifiableSS {
                 parameters: ShamirSecretSharing {
@@ -239,13 +229,52 @@ impl<E: Curve> VerifiableSS<E> {
     }
 
     pub fn get_point_commitment(&self, index: u16) -> Point<E> {
+        Self::evaluate_commitments(&self.commitments, index)
+    }
+
+    /// Evaluates commitments to polynomial coefficients at `index`
+    ///
+    /// If `commitments` are `c_0, .., c_t`, computes `c_0 + index·c_1 + .. + index^t·c_t` via
+    /// Horner's method. For commitments `c_i = a_i G` to polynomial `f`, the result is
+    /// `f(index) G` (or `f(index) G + g(index) H` for [Pedersen VSS] commitments).
+    ///
+    /// [Pedersen VSS]: super::pedersen_vss
+    ///
+    /// ## Panics
+    /// Panics if `commitments` is empty
+    pub fn evaluate_commitments(commitments: &[Point<E>], index: u16) -> Point<E> {
         let index_fe = Scalar::from(index);
-        let mut comm_iterator = self.commitments.iter().rev();
+        let mut comm_iterator = commitments.iter().rev();
         let head = comm_iterator.next().unwrap();
         let tail = comm_iterator;
         tail.fold(head.clone(), |acc, x| x + acc * &index_fe)
     }
 
+    /// Sums two commitment vectors coefficient-wise
+    ///
+    /// If `a` and `b` commit to polynomials `f` and `g`, the result commits to `f + g`. Returns
+    /// error if vectors have different lengths.
+    pub fn add_commitments(a: &[Point<E>], b: &[Point<E>]) -> Result<Vec<Point<E>>, ErrorSS> {
+        if a.len() != b.len() {
+            return Err(CommitmentsLengthMismatch);
+        }
+        Ok(a.iter().zip(b).map(|(a, b)| a + b).collect())
+    }
+
+    /// Sums commitments of all parties coefficient-wise
+    ///
+    /// In DKG, every party shares its own secret, and the commitments to the resulting group
+    /// polynomial (the first one is the group public key) are sums of all parties' commitments.
+    /// Returns error if commitments have different lengths, or empty vector if `all` is empty.
+    pub fn aggregate_commitments(all: &[Vec<Point<E>>]) -> Result<Vec<Point<E>>, ErrorSS> {
+        match all.split_first() {
+            Some((head, tail)) => tail
+                .iter()
+                .try_fold(head.clone(), |acc, c| Self::add_commitments(&acc, c)),
+            None => Ok(vec![]),
+        }
+    }
+
     //compute \lambda_{index,S}, a lagrangian coefficient that change the (t,n) scheme to (|S|,|S|)
     // used in http://stevengoldfeder.com/papers/GG18.pdf
     pub fn map_share_to_new_params(
@@ -493,4 +522,102 @@ mod tests {
         assert!(valid2.is_ok());
         assert!(valid3.is_ok());
     }
+
+    test_for_all_curves!(test_validate_share_rejects_invalid_share);
+
+    fn test_validate_share_rejects_invalid_share<E: Curve>() {
+        let secret = Scalar::random();
+
+        let (vss_scheme, secret_shares) = VerifiableSS::<E>::share(2, 5, &secret);
+
+        let tampered_share = &secret_shares[0] + Scalar::from(1);
+        assert!(vss_scheme.validate_share(&tampered_share, 1).is_err());
+
+        // valid share presented at wrong index
+        assert!(vss_scheme.validate_share(&secret_shares[0], 2).is_err());
+
+        // commitments of another sharing don't match
+        let (other_vss_scheme, _) = VerifiableSS::<E>::share(2, 5, &secret);
+        assert!(other_vss_scheme
+            .validate_share(&secret_shares[0], 1)
+            .is_err());
+    }
+
+    test_for_all_curves!(test_reconstruct_from_any_subset_of_shares);
+
+    fn test_reconstruct_from_any_subset_of_shares<E: Curve>() {
+        let secret = Scalar::random();
+
+        let (vss_scheme, secret_shares) = VerifiableSS::<E>::share(2, 5, &secret);
+
+        for i in 0..5u16 {
+            for j in i + 1..5 {
+                for k in j + 1..5 {
+                    let indices = [i, j, k];
+                    let shares = indices
+                        .iter()
+                        .map(|&x| secret_shares[usize::from(x)].clone())
+                        .collect::<Vec<_>>();
+                    assert_eq!(vss_scheme.reconstruct(&indices, &shares), secret);
+                }
+            }
+        }
+    }
+
+    test_for_all_curves!(test_aggregate_commitments);
+
+    fn test_aggregate_commitments<E: Curve>() {
+        let secret1 = Scalar::<E>::random();
+        let secret2 = Scalar::<E>::random();
+        let (vss1, shares1) = VerifiableSS::<E>::share(1, 3, &secret1);
+        let (vss2, shares2) = VerifiableSS::<E>::share(1, 3, &secret2);
+
+        let commitments = VerifiableSS::aggregate_commitments(&[
+            vss1.commitments.clone(),
+            vss2.commitments.clone(),
+        ])
+        .unwrap();
+        assert_eq!(
+            commitments,
+            VerifiableSS::add_commitments(&vss1.commitments, &vss2.commitments).unwrap()
+        );
+        assert_eq!(commitments[0], Point::generator() * (&secret1 + &secret2));
+
+        let group_vss = VerifiableSS {
+            parameters: vss1.parameters.clone(),
+            commitments,
+        };
+        for i in 0..3 {
+            let share = &shares1[i] + &shares2[i];
+            assert!(group_vss.validate_share(&share, i as u16 + 1).is_ok());
+        }
+
+        let (vss3, _) = VerifiableSS::<E>::share(2, 3, &Scalar::random());
+        assert_eq!(
+            VerifiableSS::add_commitments(&vss1.commitments, &vss3.commitments),
+            Err(ErrorSS::CommitmentsLengthMismatch)
+        );
+        assert_eq!(
+            VerifiableSS::aggregate_commitments(&[
+                vss1.commitments,
+                vss2.commitments,
+                vss3.commitments
+            ]),
+            Err(ErrorSS::CommitmentsLengthMismatch)
+        );
+        assert_eq!(VerifiableSS::<E>::aggregate_commitments(&[]), Ok(vec![]));
+    }
+
+    test_for_all_curves!(test_vss_serde_round_trip);
+
+    fn test_vss_serde_round_trip<E: Curve>() {
+        let (vss_scheme, secret_shares) = VerifiableSS::<E>::share(2, 5, &Scalar::random());
+
+        let serialized = serde_json::to_string(&vss_scheme).unwrap();
+        let deserialized: VerifiableSS<E> = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized, vss_scheme);
+        for (i, share) in secret_shares.iter().enumerate() {
+            assert!(deserialized.validate_share(share, i as u16 + 1).is_ok());
+        }
+    }
 }
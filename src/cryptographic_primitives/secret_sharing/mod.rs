@@ -6,6 +6,8 @@
 */
 
 pub mod feldman_vss;
+pub mod pedersen_vss;
 mod polynomial;
+pub mod shamir;
 
 pub use polynomial::{Polynomial, PolynomialDegree};
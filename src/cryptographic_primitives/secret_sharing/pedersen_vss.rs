@@ -0,0 +1,191 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cryptographic_primitives::commitments::pedersen_commitment::PedersenCommitment;
+use crate::cryptographic_primitives::secret_sharing::feldman_vss::{
+    ShamirSecretSharing, VerifiableSS,
+};
+use crate::cryptographic_primitives::secret_sharing::Polynomial;
+use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::ErrorSS::{self, VerifyShareError};
+
+/// Pedersen VSS, based on Torben Pryds Pedersen. 1991. Non-Interactive and Information-Theoretic
+/// Secure Verifiable Secret Sharing. In Advances in Cryptology — CRYPTO ’91, pages 129–140.
+///
+/// Unlike [Feldman VSS](VerifiableSS), commitments `C_i = a_i G + b_i H` don't reveal anything
+/// about the secret, where `a_i` are coefficients of the sharing polynomial, `b_i` are
+/// coefficients of a random blinding polynomial, and `H` is [base_point2](Point::base_point2).
+///
+/// Each party is given an index from 1,..,n, a secret share `f(i)` and a blinding share `g(i)`.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct PedersenVSS<E: Curve> {
+    pub parameters: ShamirSecretSharing,
+    pub commitments: Vec<Point<E>>,
+}
+
+/// Shared secret produced by [PedersenVSS::share]
+///
+/// Party `i` has to receive both `shares()[i-1]` and `blinding_shares()[i-1]`.
+#[derive(Clone)]
+pub struct PedersenSecretShares<E: Curve> {
+    shares: Vec<Scalar<E>>,
+    blinding_shares: Vec<Scalar<E>>,
+    polynomial: Polynomial<E>,
+    blinding_polynomial: Polynomial<E>,
+}
+
+impl<E: Curve> PedersenVSS<E> {
+    pub fn reconstruct_limit(&self) -> u16 {
+        self.parameters.threshold + 1
+    }
+
+    /// Shares a secret, `t+1` shares are required to reconstruct it
+    pub fn share(t: u16, n: u16, secret: &Scalar<E>) -> (PedersenVSS<E>, PedersenSecretShares<E>) {
+        assert!(t < n);
+        let polynomial = Polynomial::<E>::sample_exact_with_fixed_const_term(t, secret.clone());
+        let blinding_polynomial = Polynomial::<E>::sample_exact(t);
+        let shares = polynomial.evaluate_many_bigint(1..=n).collect();
+        let blinding_shares = blinding_polynomial.evaluate_many_bigint(1..=n).collect();
+
+        let commitments = polynomial
+            .coefficients()
+            .iter()
+            .zip(blinding_polynomial.coefficients())
+            .map(|(a, b)| PedersenCommitment::eval(a, b))
+            .collect();
+        (
+            PedersenVSS {
+                parameters: ShamirSecretSharing {
+                    threshold: t,
+                    share_count: n,
+                },
+                commitments,
+            },
+            PedersenSecretShares {
+                shares,
+                blinding_shares,
+                polynomial,
+                blinding_polynomial,
+            },
+        )
+    }
+
+    /// Reconstructs a secret from shares, `indices` are 0-based (share `i` is evaluated at `i+1`)
+    pub fn reconstruct(&self, indices: &[u16], shares: &[Scalar<E>]) -> Scalar<E> {
+        assert_eq!(shares.len(), indices.len());
+        assert!(shares.len() >= usize::from(self.reconstruct_limit()));
+        let points = indices
+            .iter()
+            .map(|i| Scalar::from(*i + 1))
+            .collect::<Vec<_>>();
+        VerifiableSS::<E>::lagrange_interpolation_at_zero(&points, shares)
+    }
+
+    /// Checks that `share` and `blinding_share` of party `index` match the commitments
+    pub fn validate_share(
+        &self,
+        share: &Scalar<E>,
+        blinding_share: &Scalar<E>,
+        index: u16,
+    ) -> Result<(), ErrorSS> {
+        if PedersenCommitment::eval(share, blinding_share) == self.get_point_commitment(index) {
+            Ok(())
+        } else {
+            Err(VerifyShareError)
+        }
+    }
+
+    /// Evaluates commitments at `index`, i.e. computes `f(index) G + g(index) H`
+    pub fn get_point_commitment(&self, index: u16) -> Point<E> {
+        VerifiableSS::evaluate_commitments(&self.commitments, index)
+    }
+}
+
+impl<E: Curve> PedersenSecretShares<E> {
+    /// Secret shares `f(1), .., f(n)`
+    pub fn shares(&self) -> &[Scalar<E>] {
+        &self.shares
+    }
+
+    /// Blinding shares `g(1), .., g(n)`
+    pub fn blinding_shares(&self) -> &[Scalar<E>] {
+        &self.blinding_shares
+    }
+
+    /// Polynomial `f` that was used to derive secret shares
+    pub fn polynomial(&self) -> &Polynomial<E> {
+        &self.polynomial
+    }
+
+    /// Polynomial `g` that was used to derive blinding shares
+    pub fn blinding_polynomial(&self) -> &Polynomial<E> {
+        &self.blinding_polynomial
+    }
+}
+
+impl<E: Curve> fmt::Debug for PedersenSecretShares<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // blind sensitive data stored by the structure
+        write!(f, "PedersenSecretShares{{ ... }}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_pedersen_secret_sharing_3_out_of_5);
+
+    fn test_pedersen_secret_sharing_3_out_of_5<E: Curve>() {
+        let secret = Scalar::random();
+
+        let (vss_scheme, secret_shares) = PedersenVSS::<E>::share(3, 5, &secret);
+        let shares = secret_shares.shares();
+        let blinding_shares = secret_shares.blinding_shares();
+
+        let shares_vec = vec![
+            shares[0].clone(),
+            shares[1].clone(),
+            shares[2].clone(),
+            shares[4].clone(),
+        ];
+        let secret_reconstructed = vss_scheme.reconstruct(&[0, 1, 2, 4], &shares_vec);
+        assert_eq!(secret, secret_reconstructed);
+
+        for i in 0..5 {
+            let index = i as u16 + 1;
+            assert!(vss_scheme
+                .validate_share(&shares[i], &blinding_shares[i], index)
+                .is_ok());
+        }
+    }
+
+    test_for_all_curves!(test_pedersen_validate_share_rejects_invalid_share);
+
+    fn test_pedersen_validate_share_rejects_invalid_share<E: Curve>() {
+        let (vss_scheme, secret_shares) = PedersenVSS::<E>::share(1, 3, &Scalar::random());
+        let shares = secret_shares.shares();
+        let blinding_shares = secret_shares.blinding_shares();
+
+        let tampered = &shares[0] + Scalar::from(1);
+        assert!(vss_scheme
+            .validate_share(&tampered, &blinding_shares[0], 1)
+            .is_err());
+        assert!(vss_scheme
+            .validate_share(&shares[0], &blinding_shares[1], 1)
+            .is_err());
+        assert!(vss_scheme
+            .validate_share(&shares[0], &blinding_shares[0], 2)
+            .is_err());
+    }
+}
@@ -4,7 +4,7 @@ use std::{iter, ops};
 
 use serde::{Deserialize, Serialize};
 
-use crate::elliptic::curves::{Curve, Scalar};
+use crate::elliptic::curves::{Curve, Point, Scalar};
 
 /// Degree of a [polynomial](Polynomial).
 ///
@@ -278,6 +278,36 @@ impl<E: Curve> Polynomial<E> {
         &self.coefficients
     }
 
+    /// Commits to polynomial coefficients, returns points $a_i \cdot G$ for every coefficient $a_i$
+    ///
+    /// Commitments allow anyone to compute $f(x) \cdot G$ without knowing $f$, that's how shares
+    /// are verified in [Feldman VSS](super::feldman_vss::VerifiableSS).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use curv::cryptographic_primitives::secret_sharing::Polynomial;
+    /// use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+    ///
+    /// // f(x) = 1 + 2x + 3x^2
+    /// let f = Polynomial::<Secp256k1>::from_coefficients(vec![
+    ///     Scalar::from(1),
+    ///     Scalar::from(2),
+    ///     Scalar::from(3),
+    /// ]);
+    /// assert_eq!(f.degree(), 2.into());
+    /// assert_eq!(f.evaluate(&Scalar::from(5)), Scalar::from(86));
+    ///
+    /// let commitments = f.commit();
+    /// let x = Scalar::from(5);
+    /// let f_x_g = commitments.iter().rev().fold(Point::zero(), |acc, c| acc * &x + c);
+    /// assert_eq!(f_x_g, Point::generator() * Scalar::from(86));
+    /// ```
+    pub fn commit(&self) -> Vec<Point<E>> {
+        let g = Point::<E>::generator();
+        self.coefficients.iter().map(|coef| g * coef).collect()
+    }
+
     /// Evaluates lagrange basis polynomial
     ///
     /// $$l_{X,j}(x) = \prod_{\substack{0 \leq m \leq t,\\\\m \ne j}} \frac{x - X_m}{X_j - X_m}$$
@@ -313,6 +343,21 @@ impl<E: Curve> Polynomial<E> {
     /// Generally, formula of Lagrange interpolation is:
     ///
     /// $$ L_{X,Y}(x) = \sum^t_{j=0} Y\_j \cdot l_{X,j}(x) $$
+    ///
+    /// Setting $x = 0$ gives Lagrange coefficients used to reconstruct a shared secret. Note that
+    /// `j` is an index in `xs` (counting from 0), whereas evaluation points must be non-zero:
+    /// [VerifiableSS](crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS)
+    /// maps party `i` to point $x = i + 1$. For points $1, 2, 3$ coefficients are $3, -3, 1$:
+    ///
+    /// ```rust
+    /// # use curv::cryptographic_primitives::secret_sharing::Polynomial;
+    /// # use curv::elliptic::curves::*;
+    /// let xs = &[Scalar::<Secp256k1>::from(1), Scalar::from(2), Scalar::from(3)];
+    /// let zero = Scalar::zero();
+    /// assert_eq!(Polynomial::lagrange_basis(&zero, 0, xs), Scalar::from(3));
+    /// assert_eq!(Polynomial::lagrange_basis(&zero, 1, xs), -Scalar::from(3));
+    /// assert_eq!(Polynomial::lagrange_basis(&zero, 2, xs), Scalar::from(1));
+    /// ```
     pub fn lagrange_basis(x: &Scalar<E>, j: u16, xs: &[Scalar<E>]) -> Scalar<E> {
         let x_j = &xs[usize::from(j)];
         let num: Scalar<E> = (0u16..)
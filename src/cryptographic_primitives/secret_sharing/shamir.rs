@@ -0,0 +1,135 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! Plain Shamir secret sharing
+//!
+//! Adi Shamir. 1979. How to share a secret. Commun. ACM 22, 11 (Nov. 1979), 612–613.
+//!
+//! Unlike [Feldman VSS](super::feldman_vss::VerifiableSS), no commitments are computed, so shares
+//! can't be verified, but sharing doesn't require any point multiplications.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use curv::cryptographic_primitives::secret_sharing::shamir;
+//! use curv::elliptic::curves::{Scalar, Secp256k1};
+//!
+//! let secret = Scalar::<Secp256k1>::random();
+//! let shares = shamir::share(2, 5, &secret);
+//! let reconstructed = shamir::reconstruct(2, &shares[1..4]).unwrap();
+//! assert_eq!(reconstructed, secret);
+//! ```
+
+use std::collections::HashSet;
+
+use thiserror::Error;
+
+use crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+use crate::cryptographic_primitives::secret_sharing::Polynomial;
+use crate::elliptic::curves::{Curve, Scalar};
+
+/// Shares `secret` among `n` parties, any `t+1` of them can reconstruct it
+///
+/// Returns a list of shares `(i, f(i))` for `i = 1..=n`.
+///
+/// ## Panics
+/// Panics if `t >= n`
+pub fn share<E: Curve>(t: u16, n: u16, secret: &Scalar<E>) -> Vec<(u16, Scalar<E>)> {
+    assert!(t < n);
+    let polynomial = Polynomial::<E>::sample_exact_with_fixed_const_term(t, secret.clone());
+    (1..=n)
+        .zip(polynomial.evaluate_many_bigint(1..=n))
+        .collect()
+}
+
+/// Reconstructs a secret from shares `(i, f(i))` produced by [share]
+///
+/// Threshold `t` must be the same as was used to share the secret. Note that the library can't
+/// detect if the threshold is wrong: interpolating fewer than `t+1` shares silently results
+/// into a value unrelated to the secret.
+pub fn reconstruct<E: Curve>(
+    t: u16,
+    shares: &[(u16, Scalar<E>)],
+) -> Result<Scalar<E>, ReconstructError> {
+    if shares.len() <= usize::from(t) {
+        return Err(ReconstructError::NotEnoughShares {
+            required: usize::from(t) + 1,
+            provided: shares.len(),
+        });
+    }
+    let mut indices = HashSet::with_capacity(shares.len());
+    if !shares.iter().all(|(i, _)| *i != 0 && indices.insert(*i)) {
+        return Err(ReconstructError::InvalidIndices);
+    }
+
+    let (points, values): (Vec<_>, Vec<_>) = shares
+        .iter()
+        .map(|(i, share)| (Scalar::from(*i), share.clone()))
+        .unzip();
+    Ok(VerifiableSS::<E>::lagrange_interpolation_at_zero(
+        &points, &values,
+    ))
+}
+
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum ReconstructError {
+    #[error("not enough shares: at least {required} required, {provided} provided")]
+    NotEnoughShares { required: usize, provided: usize },
+    #[error("share indices must be nonzero and distinct")]
+    InvalidIndices,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(reconstructs_secret_from_any_t_plus_1_shares);
+    fn reconstructs_secret_from_any_t_plus_1_shares<E: Curve>() {
+        let secret = Scalar::<E>::random();
+        let shares = share(2, 5, &secret);
+        assert_eq!(shares.len(), 5);
+
+        for subset in [[0, 1, 2], [0, 2, 4], [4, 3, 1]] {
+            let subset: Vec<_> = subset.iter().map(|&i| shares[i].clone()).collect();
+            assert_eq!(reconstruct(2, &subset).unwrap(), secret);
+        }
+        assert_eq!(reconstruct(2, &shares).unwrap(), secret);
+    }
+
+    test_for_all_curves!(reconstruct_signals_not_enough_shares);
+    fn reconstruct_signals_not_enough_shares<E: Curve>() {
+        let secret = Scalar::<E>::random();
+        let shares = share(2, 5, &secret);
+
+        assert_eq!(
+            reconstruct(2, &shares[..2]),
+            Err(ReconstructError::NotEnoughShares {
+                required: 3,
+                provided: 2
+            })
+        );
+        // With wrong threshold, fewer shares are interpolated into an unrelated value
+        assert_ne!(reconstruct(1, &shares[..2]).unwrap(), secret);
+    }
+
+    test_for_all_curves!(reconstruct_rejects_invalid_indices);
+    fn reconstruct_rejects_invalid_indices<E: Curve>() {
+        let shares = share(1, 3, &Scalar::<E>::random());
+
+        let duplicated = vec![shares[0].clone(), shares[0].clone()];
+        assert_eq!(
+            reconstruct(1, &duplicated),
+            Err(ReconstructError::InvalidIndices)
+        );
+        let zero_index = vec![(0, shares[0].1.clone()), shares[1].clone()];
+        assert_eq!(
+            reconstruct(1, &zero_index),
+            Err(ReconstructError::InvalidIndices)
+        );
+    }
+}
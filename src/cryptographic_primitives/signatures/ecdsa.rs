@@ -0,0 +1,237 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+//! ECDSA signatures with deterministic nonces
+//!
+//! Nonces are derived as specified in [RFC6979](https://tools.ietf.org/html/rfc6979) instantiated
+//! with HMAC-SHA256 (see [rfc6979]), produced signatures are normalized to low `s` (`s <= q/2`). For secp256k1
+//! this matches signatures produced by [libsecp256k1](https://github.com/bitcoin-core/secp256k1).
+//!
+//! ECDSA is defined over short Weierstrass curves, i.e. [Secp256k1] and [Secp256r1], which are
+//! marked by [EcdsaCurve] trait. It shouldn't be used with Edwards curves (negating a point changes
+//! its x coordinate) and can't be used with Ristretto (points don't expose coordinates).
+//!
+//! ## Example
+//!
+//! ```rust
+//! use curv::arithmetic::*;
+//! use curv::cryptographic_primitives::hashing::{Digest, DigestExt};
+//! use curv::cryptographic_primitives::signatures::ecdsa;
+//! use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+//! use sha2::Sha256;
+//!
+//! let secret_key = Scalar::<Secp256k1>::random();
+//! let public_key = Point::generator() * &secret_key;
+//!
+//! let message_hash = Sha256::new().chain(b"hello world").result_bigint();
+//! let signature = ecdsa::sign(&message_hash, &secret_key);
+//! assert!(ecdsa::verify(&message_hash, &signature, &public_key).is_ok());
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::arithmetic::*;
+use crate::cryptographic_primitives::hashing::rfc6979;
+use crate::elliptic::curves::{Curve, Point, Scalar, Secp256k1, Secp256r1};
+
+use super::InvalidSignature;
+
+/// Curves over which ECDSA is defined
+///
+/// Implemented for short Weierstrass curves whose points expose x coordinate, see
+/// [module-level docs](self).
+pub trait EcdsaCurve: Curve {}
+
+impl EcdsaCurve for Secp256k1 {}
+impl EcdsaCurve for Secp256r1 {}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Signature<E: Curve> {
+    pub r: Scalar<E>,
+    pub s: Scalar<E>,
+}
+
+/// Signs a message hash
+///
+/// `message_hash` must be a hash of the message whose bit length doesn't exceed bit length of
+/// group order (e.g. SHA256 for secp256k1). It's interpreted modulo group order.
+///
+/// Nonce is derived by [rfc6979::NonceGenerator]. If it produces `r = 0` or `s = 0` (which happens
+/// with negligible probability), next nonce is taken as RFC6979 prescribes.
+///
+/// ## Panics
+/// Panics if `secret_key` is zero
+pub fn sign<E: EcdsaCurve>(message_hash: &BigInt, secret_key: &Scalar<E>) -> Signature<E> {
+    assert!(!secret_key.is_zero(), "secret key must be nonzero");
+    let z = Scalar::<E>::from_bigint(message_hash);
+
+    let (r, s) = rfc6979::NonceGenerator::new(secret_key, &z.to_bigint())
+        .find_map(|k| {
+            let r = (Point::generator() * &k)
+                .x_coord()
+                .map(|x| Scalar::<E>::from_bigint(&x))
+                .expect("k*G is nonzero, its x coordinate is defined for ECDSA curves");
+            let s: Scalar<E> = k.invert().expect("k is nonzero") * (&z + &r * secret_key);
+            if r.is_zero() || s.is_zero() {
+                None
+            } else {
+                Some((r, s))
+            }
+        })
+        .expect("nonce generator never ends");
+
+    let half_order: BigInt = Scalar::<E>::group_order() >> 1;
+    let s = if s.to_bigint() > half_order { -s } else { s };
+    Signature { r, s }
+}
+
+/// Verifies a signature of the message hash
+///
+/// Both low and high `s` signatures are accepted.
+pub fn verify<E: EcdsaCurve>(
+    message_hash: &BigInt,
+    signature: &Signature<E>,
+    public_key: &Point<E>,
+) -> Result<(), InvalidSignature> {
+    if signature.r.is_zero() || signature.s.is_zero() || public_key.is_zero() {
+        return Err(InvalidSignature);
+    }
+    let z = Scalar::<E>::from_bigint(message_hash);
+    let w = signature.s.invert().ok_or(InvalidSignature)?;
+    let u1 = z * &w;
+    let u2 = &signature.r * &w;
+
+    let point = Point::generator() * u1 + public_key * u2;
+    let x = point.x_coord().ok_or(InvalidSignature)?;
+    if Scalar::<E>::from_bigint(&x) == signature.r {
+        Ok(())
+    } else {
+        Err(InvalidSignature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use secp256k1::{Message, SecretKey, SECP256K1};
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+    use crate::cryptographic_primitives::hashing::DigestExt;
+
+    #[test]
+    fn sign_verify_secp256k1() {
+        sign_verify::<Secp256k1>()
+    }
+
+    #[test]
+    fn sign_verify_secp256r1() {
+        sign_verify::<Secp256r1>()
+    }
+
+    fn sign_verify<E: EcdsaCurve>() {
+        let secret_key = Scalar::<E>::random();
+        let public_key = Point::generator() * &secret_key;
+        let message_hash = Sha256::new().chain(b"message").result_bigint();
+
+        let signature = sign(&message_hash, &secret_key);
+        assert!(verify(&message_hash, &signature, &public_key).is_ok());
+        assert_eq!(signature, sign(&message_hash, &secret_key));
+
+        let other_hash = Sha256::new().chain(b"other message").result_bigint();
+        assert!(verify(&other_hash, &signature, &public_key).is_err());
+        let other_public_key = Point::generator() * Scalar::random();
+        assert!(verify(&message_hash, &signature, &other_public_key).is_err());
+        let tampered = Signature {
+            r: signature.r.clone(),
+            s: &signature.s + Scalar::from(1),
+        };
+        assert!(verify(&message_hash, &tampered, &public_key).is_err());
+        let zero_r = Signature {
+            r: Scalar::zero(),
+            s: signature.s.clone(),
+        };
+        assert!(verify(&message_hash, &zero_r, &public_key).is_err());
+    }
+
+    #[test]
+    fn signature_is_low_s_secp256k1() {
+        signature_is_low_s::<Secp256k1>()
+    }
+
+    #[test]
+    fn signature_is_low_s_secp256r1() {
+        signature_is_low_s::<Secp256r1>()
+    }
+
+    fn signature_is_low_s<E: EcdsaCurve>() {
+        let half_order: BigInt = Scalar::<E>::group_order() >> 1;
+        let secret_key = Scalar::<E>::random();
+        let public_key = Point::generator() * &secret_key;
+        for i in 0u8..10 {
+            let message_hash = Sha256::new().chain([i]).result_bigint();
+            let signature = sign(&message_hash, &secret_key);
+            assert!(signature.s.to_bigint() <= half_order);
+
+            // High-s counterpart is still a valid signature
+            let high_s = Signature {
+                r: signature.r.clone(),
+                s: -&signature.s,
+            };
+            assert!(verify(&message_hash, &high_s, &public_key).is_ok());
+        }
+    }
+
+    #[test]
+    fn secp256k1_test_vectors() {
+        // (secret key, message, r, s)
+        let vectors = [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "Satoshi Nakamoto",
+                "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8",
+                "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+            ),
+            (
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "All those moments will be lost in time, like tears in rain. Time to die...",
+                "8600dbd41e348fe5c9465ab92d23e3db8b98b873beecd930736488696438cb6b",
+                "547fe64427496db33bf66019dacbf0039c04199abb0122918601db38a72cfc21",
+            ),
+        ];
+        for (secret_key, message, r, s) in vectors.iter() {
+            let secret_key =
+                Scalar::<Secp256k1>::from_bigint(&BigInt::from_hex(secret_key).unwrap());
+            let public_key = Point::generator() * &secret_key;
+            let message_hash = Sha256::new().chain(message).result_bigint();
+
+            let signature = sign(&message_hash, &secret_key);
+            assert_eq!(signature.r.to_bigint(), BigInt::from_hex(r).unwrap());
+            assert_eq!(signature.s.to_bigint(), BigInt::from_hex(s).unwrap());
+            assert!(verify(&message_hash, &signature, &public_key).is_ok());
+        }
+    }
+
+    #[test]
+    fn secp256k1_signatures_match_libsecp256k1() {
+        for i in 0u8..20 {
+            let secret_key = Scalar::<Secp256k1>::random();
+            let message_hash = Sha256::digest(&[i]);
+
+            let signature = sign(&BigInt::from_bytes(&message_hash), &secret_key);
+
+            let expected = SECP256K1
+                .sign(
+                    &Message::from_slice(&message_hash).unwrap(),
+                    &SecretKey::from_slice(&secret_key.to_bytes()).unwrap(),
+                )
+                .serialize_compact();
+            assert_eq!(&signature.r.to_bytes()[..], &expected[..32]);
+            assert_eq!(&signature.s.to_bytes()[..], &expected[32..]);
+        }
+    }
+}
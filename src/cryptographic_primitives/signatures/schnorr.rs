@@ -0,0 +1,431 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+//! Schnorr signatures over secp256k1 as specified in [BIP340]
+//!
+//! Public keys are x-only: a public key is a 32 bytes x coordinate of the point `P = d·G`, the
+//! point with even y coordinate is implied. Signatures are 64 bytes `x(R) || s` and are
+//! compatible with Bitcoin Taproot.
+//!
+//! [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+//!
+//! ## Example
+//!
+//! ```rust
+//! use curv::cryptographic_primitives::signatures::schnorr;
+//! use curv::elliptic::curves::{Scalar, Secp256k1};
+//!
+//! let secret_key = Scalar::<Secp256k1>::random();
+//! let public_key = schnorr::public_key(&secret_key);
+//!
+//! let signature = schnorr::sign(b"hello world", &secret_key);
+//! assert!(schnorr::verify(b"hello world", &signature, &public_key).is_ok());
+//!
+//! let bytes = signature.to_bytes();
+//! let parsed = schnorr::Signature::from_bytes(&bytes).unwrap();
+//! assert_eq!(parsed, signature);
+//! ```
+
+use std::convert::TryInto;
+
+use rand::{rngs::OsRng, RngCore};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_bytes::ByteBuf;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::arithmetic::*;
+use crate::elliptic::curves::secp256_k1::Secp256k1Point;
+use crate::elliptic::curves::{Point, Scalar, Secp256k1};
+
+use super::InvalidSignature;
+
+/// BIP340 signature `(R, s)`
+///
+/// Nonce point `R` always has even y coordinate, so it's serialized as its x coordinate only.
+/// Serde implementation uses the same 64 bytes encoding as [to_bytes](Self::to_bytes) (as hex
+/// string in human-readable formats), so deserialization performs the same checks as
+/// [from_bytes](Self::from_bytes).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Signature {
+    pub r: Point<Secp256k1>,
+    pub s: Scalar<Secp256k1>,
+}
+
+impl Signature {
+    /// Serializes the signature as `x(R) || s`
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&x_only(&self.r));
+        bytes[32..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+
+    /// Parses the signature `x(R) || s`
+    ///
+    /// Returns error if `x(R)` is not an x coordinate of any point, or if `s` is not less than
+    /// group order.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Result<Self, InvalidSignature> {
+        Ok(Signature {
+            r: lift_x(&bytes[..32])?,
+            s: Scalar::from_bytes(&bytes[32..]).map_err(|_| InvalidSignature)?,
+        })
+    }
+}
+
+impl Serialize for Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let bytes = self.to_bytes();
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&hex::encode(bytes))
+        } else {
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = if deserializer.is_human_readable() {
+            let hex = String::deserialize(deserializer)?;
+            hex::decode(hex).map_err(D::Error::custom)?
+        } else {
+            ByteBuf::deserialize(deserializer)?.into_vec()
+        };
+        let bytes: &[u8; 64] = bytes
+            .as_slice()
+            .try_into()
+            .map_err(|_| D::Error::invalid_length(bytes.len(), &"64 bytes of BIP340 signature"))?;
+        Signature::from_bytes(bytes).map_err(|_| D::Error::custom("invalid BIP340 signature"))
+    }
+}
+
+/// Derives x-only public key from the secret key
+///
+/// ## Panics
+/// Panics if `secret_key` is zero
+pub fn public_key(secret_key: &Scalar<Secp256k1>) -> [u8; 32] {
+    assert!(!secret_key.is_zero(), "secret key must be nonzero");
+    x_only(&(Point::generator() * secret_key))
+}
+
+/// Signs a message using randomly generated auxiliary data
+///
+/// ## Panics
+/// Panics if `secret_key` is zero
+pub fn sign(message: &[u8], secret_key: &Scalar<Secp256k1>) -> Signature {
+    let mut aux_rand = Zeroizing::new([0u8; 32]);
+    OsRng.fill_bytes(&mut *aux_rand);
+    sign_with_aux_rand(message, secret_key, &aux_rand)
+}
+
+/// Signs a message using given auxiliary data
+///
+/// Nonce is derived deterministically from the secret key, message and `aux_rand`. Auxiliary
+/// data doesn't need to be secret, but using fresh randomness protects against side-channel
+/// attacks. Intended for reproducing test vectors, prefer [sign] otherwise.
+///
+/// ## Panics
+/// Panics if `secret_key` is zero
+pub fn sign_with_aux_rand(
+    message: &[u8],
+    secret_key: &Scalar<Secp256k1>,
+    aux_rand: &[u8; 32],
+) -> Signature {
+    assert!(!secret_key.is_zero(), "secret key must be nonzero");
+    let public_key = Point::generator() * secret_key;
+    let d = if has_even_y(&public_key) {
+        secret_key.clone()
+    } else {
+        -secret_key
+    };
+    let public_key = x_only(&public_key);
+
+    let mut t = Zeroizing::new([0u8; 32]);
+    let aux_hash = tagged_hash(b"BIP0340/aux", &[aux_rand]);
+    for ((t, d), a) in t.iter_mut().zip(d.to_bytes().iter()).zip(aux_hash.iter()) {
+        *t = d ^ a;
+    }
+    let rand = Zeroizing::new(tagged_hash(
+        b"BIP0340/nonce",
+        &[&t[..], &public_key, message],
+    ));
+    let k = Scalar::<Secp256k1>::from_bytes_mod_order(&rand[..]);
+    assert!(
+        !k.is_zero(),
+        "the probability of reaching this is negligible"
+    );
+
+    let r = Point::generator() * &k;
+    let (r, k) = if has_even_y(&r) { (r, k) } else { (-r, -k) };
+    let e = challenge(&x_only(&r), &public_key, message);
+    Signature { s: k + e * d, r }
+}
+
+/// Verifies a signature of the message under x-only public key
+pub fn verify(
+    message: &[u8],
+    signature: &Signature,
+    public_key: &[u8; 32],
+) -> Result<(), InvalidSignature> {
+    let p = lift_x(public_key)?;
+    let e = challenge(&x_only(&signature.r), public_key, message);
+    let r = Point::generator() * &signature.s - p * e;
+    if !r.is_zero() && has_even_y(&r) && r == signature.r {
+        Ok(())
+    } else {
+        Err(InvalidSignature)
+    }
+}
+
+/// Computes `SHA256(SHA256(tag) || SHA256(tag) || data)`
+fn tagged_hash(tag: &[u8], data: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag);
+    let mut hasher = Sha256::new().chain(tag_hash).chain(tag_hash);
+    for d in data {
+        hasher.update(d);
+    }
+    hasher.finalize().into()
+}
+
+fn challenge(r: &[u8], public_key: &[u8], message: &[u8]) -> Scalar<Secp256k1> {
+    let e = tagged_hash(b"BIP0340/challenge", &[r, public_key, message]);
+    Scalar::from_bytes_mod_order(&e)
+}
+
+fn has_even_y(point: &Point<Secp256k1>) -> bool {
+    point.y_coord().map(|y| !y.test_bit(0)).unwrap_or(false)
+}
+
+fn x_only(point: &Point<Secp256k1>) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes.copy_from_slice(&point.to_bytes(true)[1..]);
+    bytes
+}
+
+/// Returns the point with given x coordinate and even y coordinate
+fn lift_x(x: &[u8]) -> Result<Point<Secp256k1>, InvalidSignature> {
+    let point = Secp256k1Point::from_x_coord(&BigInt::from_bytes(x), false)
+        .map_err(|_| InvalidSignature)?;
+    Point::from_raw(point).map_err(|_| InvalidSignature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_verify() {
+        let secret_key = Scalar::<Secp256k1>::random();
+        let public_key = public_key(&secret_key);
+
+        let signature = sign(b"message", &secret_key);
+        assert!(verify(b"message", &signature, &public_key).is_ok());
+        assert_eq!(
+            Signature::from_bytes(&signature.to_bytes()).unwrap(),
+            signature
+        );
+
+        assert!(verify(b"other message", &signature, &public_key).is_err());
+        let other_public_key = super::public_key(&Scalar::random());
+        assert!(verify(b"message", &signature, &other_public_key).is_err());
+        let tampered = Signature {
+            r: signature.r.clone(),
+            s: &signature.s + Scalar::from(1),
+        };
+        assert!(verify(b"message", &tampered, &public_key).is_err());
+    }
+
+    /// Test vectors from [BIP340](https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv)
+    #[test]
+    fn bip340_signing_test_vectors() {
+        // (secret key, public key, aux_rand, message, signature)
+        let vectors = [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA8215\
+                 25F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+            ),
+            (
+                "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE3341\
+                 8906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+            ),
+            (
+                "C90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B14E5C9",
+                "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8",
+                "C87AA53824B4D7AE2EB035A2B5BBBCCC080E76CDC6D1692C4B0B62D798E6D906",
+                "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C",
+                "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1B\
+                 AB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7",
+            ),
+            (
+                "0B432B2677937381AEF05BB02A66ECD012773062CF3FA2549E44F58ED2401710",
+                "25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517",
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+                "7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC\
+                 97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3",
+            ),
+        ];
+        for (secret_key, expected_public_key, aux_rand, message, expected_signature) in
+            vectors.iter()
+        {
+            let secret_key = Scalar::<Secp256k1>::from_hex(secret_key).unwrap();
+            let aux_rand = from_hex::<32>(aux_rand);
+            let message = hex::decode(message).unwrap();
+
+            let public_key = public_key(&secret_key);
+            assert_eq!(public_key, from_hex::<32>(expected_public_key));
+            let signature = sign_with_aux_rand(&message, &secret_key, &aux_rand);
+            assert_eq!(signature.to_bytes(), from_hex::<64>(expected_signature));
+            assert!(verify(&message, &signature, &public_key).is_ok());
+        }
+    }
+
+    /// Test vectors from [BIP340](https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv)
+    #[test]
+    fn bip340_verification_test_vectors() {
+        // (public key, message, signature, result)
+        let vectors = [
+            (
+                "D69C3509BB99E412E68B0FE8544E72837DFA30746D8BE2AA65975F29D22DC7B9",
+                "4DF3C3F68FCC83B27E9D42C90431A72499F17875C81A599B566C9889B9696703",
+                "00000000000000000000003B78CE563F89A0ED9414F5AA28AD0D96D6795F9C63\
+                 76AFB1548AF603B3EB45C9F8207DEE1060CB71C04E80F593060B07D28308D7F4",
+                true,
+            ),
+            // public key not on the curve
+            (
+                "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769\
+                 69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+                false,
+            ),
+            // has_even_y(R) is false
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "FFF97BD5755EEEA420453A14355235D382F6472F8568A18B2F057A1460297556\
+                 3CC27944640AC607CD107AE10923D9EF7A73C643E166BE5EBEAFA34B1AC553E2",
+                false,
+            ),
+            // negated message
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "1FA62E331EDBC21C394792D2AB1100A7B432B013DF3F6FF4F99FCB33E0E1515F\
+                 28890B3EDB6E7189B630448B515CE4F8622A954CFE545735AAEA5134FCCDB2BD",
+                false,
+            ),
+            // negated s value
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769\
+                 961764B3AA9B2FFCB6EF947B6887A226E8D7C93E00C5ED0C1834FF0D0C2E6DA6",
+                false,
+            ),
+            // sG - eP is infinite
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "0000000000000000000000000000000000000000000000000000000000000000\
+                 123DDA8328AF9C23A94C1FEECFD123BA4FB73476F0D594DCB65C6425BD186051",
+                false,
+            ),
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "0000000000000000000000000000000000000000000000000000000000000001\
+                 7615FBAF5AE28864013C099742DEADB4DBA87F11AC6754F93780D5A1837CF197",
+                false,
+            ),
+            // sig[0:32] is not an x coordinate on the curve
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "4A298DACAE57395A15D0795DDBFD1DCB564DA82B0F269BC70A74F8220429BA1D\
+                 69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+                false,
+            ),
+            // sig[0:32] is equal to field size
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F\
+                 69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+                false,
+            ),
+            // sig[32:64] is equal to curve order
+            (
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769\
+                 FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+                false,
+            ),
+            // public key exceeds field size
+            (
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC30",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6CFF5C3BA86C69EA4B7376F31A9BCB4F74C1976089B2D9963DA2E5543E177769\
+                 69E89B4C5564D00349106B8497785DD7D1D713A8AE82B32FA79D5F7FC407D39B",
+                false,
+            ),
+        ];
+        for (i, (public_key, message, signature, expected)) in vectors.iter().enumerate() {
+            let public_key = from_hex::<32>(public_key);
+            let message = hex::decode(message).unwrap();
+            let result = Signature::from_bytes(&from_hex::<64>(signature))
+                .and_then(|signature| verify(&message, &signature, &public_key));
+            assert_eq!(result.is_ok(), *expected, "vector {}", i);
+        }
+    }
+
+    #[test]
+    fn serde_uses_64_bytes_encoding() {
+        let signature = sign(b"message", &Scalar::random());
+
+        let json = serde_json::to_string(&signature).unwrap();
+        assert_eq!(json, format!("\"{}\"", hex::encode(signature.to_bytes())));
+        assert_eq!(serde_json::from_str::<Signature>(&json).unwrap(), signature);
+
+        let bin = bincode::serialize(&signature).unwrap();
+        assert_eq!(&bin[bin.len() - 64..], &signature.to_bytes()[..]);
+        assert_eq!(bincode::deserialize::<Signature>(&bin).unwrap(), signature);
+
+        // s is not less than group order
+        let mut bytes = signature.to_bytes();
+        bytes[32..].copy_from_slice(&[0xff; 32]);
+        let json = format!("\"{}\"", hex::encode(bytes));
+        assert!(serde_json::from_str::<Signature>(&json).is_err());
+        // wrong length
+        let json = format!("\"{}\"", hex::encode(&signature.to_bytes()[..63]));
+        assert!(serde_json::from_str::<Signature>(&json).is_err());
+    }
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        bytes.copy_from_slice(&hex::decode(hex).unwrap());
+        bytes
+    }
+}
@@ -0,0 +1,192 @@
+//! Fiat-Shamir transcript
+//!
+//! [Transcript] accumulates everything prover and verifier exchanged (points, scalars, arbitrary
+//! messages) and derives challenges from it. Every appended item is bound to a label and framed
+//! with length prefixes (similarly to [Merlin](https://merlin.cool)), so that different sequences
+//! of appends never result into the same hash input.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use curv::cryptographic_primitives::transcript::Transcript;
+//! use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+//!
+//! let pk = Point::<Secp256k1>::generator() * Scalar::random();
+//!
+//! let mut prover: Transcript = Transcript::new(b"my-protocol");
+//! prover.append_point(b"pk", &pk);
+//! let challenge = prover.challenge_scalar::<Secp256k1>(b"challenge");
+//!
+//! let mut verifier: Transcript = Transcript::new(b"my-protocol");
+//! verifier.append_point(b"pk", &pk);
+//! assert_eq!(challenge, verifier.challenge_scalar(b"challenge"));
+//! ```
+//!
+//! When the whole input is known upfront and is just a list of points and scalars, [challenge]
+//! is a shortcut:
+//!
+//! ```rust
+//! use curv::cryptographic_primitives::transcript;
+//! use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+//!
+//! let x = Scalar::<Secp256k1>::random();
+//! let r = Scalar::<Secp256k1>::random();
+//! let (pk, commitment) = (Point::generator() * &x, Point::generator() * &r);
+//!
+//! let e = transcript::challenge(&[&pk, &commitment], &[]);
+//! let z = r + &e * x;
+//! assert_eq!(Point::generator() * z, commitment + pk * e);
+//! ```
+
+use digest::Digest;
+use sha2::Sha256;
+
+use crate::cryptographic_primitives::hashing::DigestExt;
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+/// Fiat-Shamir transcript, see [module level documentation](self)
+#[derive(Clone)]
+pub struct Transcript<H: Digest + Clone = Sha256> {
+    hasher: H,
+}
+
+impl<H: Digest + Clone> Transcript<H> {
+    /// Constructs a new transcript separated by protocol `label`
+    pub fn new(label: &[u8]) -> Self {
+        let mut transcript = Transcript { hasher: H::new() };
+        transcript.append_message(b"dom-sep", label);
+        transcript
+    }
+
+    /// Appends labeled arbitrary message
+    pub fn append_message(&mut self, label: &[u8], message: &[u8]) {
+        self.hasher.update((label.len() as u64).to_le_bytes());
+        self.hasher.update(label);
+        self.hasher.update((message.len() as u64).to_le_bytes());
+        self.hasher.update(message);
+    }
+
+    /// Appends labeled point in compressed form
+    pub fn append_point<E: Curve>(&mut self, label: &[u8], point: &Point<E>) {
+        self.append_message(label, &point.to_bytes(true))
+    }
+
+    /// Appends labeled scalar
+    pub fn append_scalar<E: Curve>(&mut self, label: &[u8], scalar: &Scalar<E>) {
+        self.append_message(label, &scalar.to_bytes())
+    }
+
+    /// Derives a challenge from everything appended so far
+    ///
+    /// Challenge is appended to the transcript, so consecutive challenges are different.
+    pub fn challenge_scalar<E: Curve>(&mut self, label: &[u8]) -> Scalar<E> {
+        self.append_message(label, b"");
+        let challenge: Scalar<E> = self.hasher.clone().result_scalar();
+        self.append_scalar(b"challenge", &challenge);
+        challenge
+    }
+}
+
+/// Hashes `points` followed by `scalars` into a challenge
+///
+/// Every point is appended to a SHA256 [Transcript] with label `"point"`, every scalar with label
+/// `"scalar"`, so a point can never be confused with a scalar that has the same encoding. The
+/// challenge is then derived via [challenge_scalar](Transcript::challenge_scalar).
+pub fn challenge<E: Curve>(points: &[&Point<E>], scalars: &[&Scalar<E>]) -> Scalar<E> {
+    let mut transcript = Transcript::<Sha256>::new(b"CURV-challenge");
+    for point in points {
+        transcript.append_point(b"point", point);
+    }
+    for scalar in scalars {
+        transcript.append_scalar(b"scalar", scalar);
+    }
+    transcript.challenge_scalar(b"challenge")
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::{Sha256, Sha512};
+
+    use crate::elliptic::curves::{Curve, Ed25519, Point, Ristretto, Scalar};
+    use crate::test_for_all_curves;
+
+    use super::{challenge, Transcript};
+
+    test_for_all_curves!(reordering_appends_changes_challenge);
+    fn reordering_appends_changes_challenge<E: Curve>() {
+        let a = Point::<E>::generator() * Scalar::random();
+        let b = Point::<E>::generator() * Scalar::random();
+
+        let mut t1 = Transcript::<Sha256>::new(b"test");
+        t1.append_point(b"a", &a);
+        t1.append_point(b"b", &b);
+
+        let mut t2 = t1.clone();
+
+        let mut t3 = Transcript::<Sha256>::new(b"test");
+        t3.append_point(b"b", &b);
+        t3.append_point(b"a", &a);
+
+        let c1 = t1.challenge_scalar::<E>(b"c");
+        assert_eq!(c1, t2.challenge_scalar(b"c"));
+        assert_ne!(c1, t3.challenge_scalar(b"c"));
+    }
+
+    test_for_all_curves!(framing_separates_labels_and_messages);
+    fn framing_separates_labels_and_messages<E: Curve>() {
+        let mut t1 = Transcript::<Sha512>::new(b"test");
+        t1.append_message(b"ab", b"c");
+        let mut t2 = Transcript::<Sha512>::new(b"test");
+        t2.append_message(b"a", b"bc");
+        assert_ne!(t1.challenge_scalar::<E>(b"c"), t2.challenge_scalar(b"c"));
+
+        let mut t3 = Transcript::<Sha512>::new(b"other protocol");
+        t3.append_message(b"ab", b"c");
+        let mut t1 = Transcript::<Sha512>::new(b"test");
+        t1.append_message(b"ab", b"c");
+        assert_ne!(t1.challenge_scalar::<E>(b"c"), t3.challenge_scalar(b"c"));
+    }
+
+    test_for_all_curves!(consecutive_challenges_differ);
+    fn consecutive_challenges_differ<E: Curve>() {
+        let mut t = Transcript::<Sha256>::new(b"test");
+        t.append_scalar(b"s", &Scalar::<E>::random());
+        let c1 = t.challenge_scalar::<E>(b"c");
+        let c2 = t.challenge_scalar::<E>(b"c");
+        assert_ne!(c1, c2);
+    }
+
+    test_for_all_curves!(challenge_depends_on_whole_transcript);
+    fn challenge_depends_on_whole_transcript<E: Curve>() {
+        let p1 = Point::<E>::generator() * Scalar::random();
+        let p2 = Point::<E>::generator() * Scalar::random();
+        let s = Scalar::<E>::random();
+
+        let e = challenge(&[&p1, &p2], &[&s]);
+        assert_eq!(e, challenge(&[&p1, &p2], &[&s]));
+        assert_ne!(e, challenge(&[&p2, &p1], &[&s]));
+        assert_ne!(e, challenge(&[&p1, &p2], &[]));
+        assert_ne!(e, challenge(&[&p1], &[&s]));
+        assert_ne!(challenge::<E>(&[], &[]), challenge(&[&Point::zero()], &[]));
+    }
+
+    #[test]
+    fn point_and_scalar_with_same_bytes_are_separated_ed25519() {
+        point_and_scalar_with_same_bytes_are_separated::<Ed25519>()
+    }
+
+    #[test]
+    fn point_and_scalar_with_same_bytes_are_separated_ristretto() {
+        point_and_scalar_with_same_bytes_are_separated::<Ristretto>()
+    }
+
+    /// Only applicable to curves whose compressed points and scalars have the same length
+    fn point_and_scalar_with_same_bytes_are_separated<E: Curve>() {
+        let (point, scalar) = std::iter::repeat_with(Scalar::<E>::random)
+            .find_map(|s| Point::<E>::from_bytes(&s.to_bytes()).ok().map(|p| (p, s)))
+            .unwrap();
+        assert_eq!(&*point.to_bytes(true), &*scalar.to_bytes());
+
+        assert_ne!(challenge(&[&point], &[]), challenge(&[], &[&scalar]));
+    }
+}
@@ -10,11 +10,13 @@ use std::convert::TryInto;
 use std::ptr;
 use std::sync::atomic;
 
-use curve25519_dalek::constants::{BASEPOINT_ORDER, RISTRETTO_BASEPOINT_POINT};
+use curve25519_dalek::constants::{
+    BASEPOINT_ORDER, RISTRETTO_BASEPOINT_POINT, RISTRETTO_BASEPOINT_TABLE,
+};
 use curve25519_dalek::ristretto::CompressedRistretto;
 use curve25519_dalek::traits::{Identity, IsIdentity};
 use generic_array::GenericArray;
-use rand::thread_rng;
+use rand::rngs::OsRng;
 use sha2::{Digest, Sha256};
 use zeroize::{Zeroize, Zeroizing};
 
@@ -89,7 +91,7 @@ impl ECScalar for RistrettoScalar {
     fn random() -> RistrettoScalar {
         RistrettoScalar {
             purpose: "random",
-            fe: SK::random(&mut thread_rng()).into(),
+            fe: SK::random(&mut OsRng).into(),
         }
     }
 
@@ -291,6 +293,15 @@ impl ECPoint for RistrettoPoint {
         }
     }
 
+    /// Uses precomputed table of generator multiples which is several times faster than
+    /// variable-base multiplication
+    fn generator_mul(scalar: &Self::Scalar) -> Self {
+        RistrettoPoint {
+            purpose: "generator_mul",
+            ge: &*scalar.fe * &RISTRETTO_BASEPOINT_TABLE,
+        }
+    }
+
     fn add_point(&self, other: &Self) -> RistrettoPoint {
         RistrettoPoint {
             purpose: "add_point",
@@ -347,3 +358,53 @@ impl Zeroize for RistrettoPoint {
         atomic::compiler_fence(atomic::Ordering::SeqCst);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ECPoint, ECScalar, FE, GE};
+
+    #[test]
+    fn generator_mul_matches_scalar_mul() {
+        for _ in 0..20 {
+            let s = FE::random();
+            assert_eq!(GE::generator_mul(&s), GE::generator().scalar_mul(&s));
+        }
+    }
+
+    #[test]
+    fn deserializes_canonical_encodings() {
+        let identity = GE::deserialize(&[0u8; 32]).unwrap();
+        assert!(identity.is_zero());
+
+        let generator =
+            hex::decode("e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76")
+                .unwrap();
+        assert_eq!(&GE::deserialize(&generator).unwrap(), GE::generator());
+        assert_eq!(&GE::generator().serialize_compressed()[..], &generator[..]);
+    }
+
+    #[test]
+    fn rejects_non_canonical_encodings() {
+        // Bad encodings taken from https://ristretto.group/test_vectors/ristretto255.html
+        let bad_encodings = [
+            // Non-canonical field encodings
+            "00ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "f3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            // Negative field elements
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f",
+            "ed57ffd8c914fb201471d1c3d245ce3c746fcbe63a3679d51b6a516ebebe0e20",
+            // Non-square x^2
+            "26948d35ca62e643e26a83177332e6b6afeb9d08e4268b650f1f5bbd8d81d371",
+            "4eac077a713c57b4f4397629a4145982c661f48044dd3f96427d40b147d9742f",
+            // Negative xy value
+            "3eb858e78f5a7254d8c9731174a94f76755fd3941c0ac93735c07ba14579630e",
+        ];
+        for encoding in bad_encodings.iter() {
+            let bytes = hex::decode(encoding).unwrap();
+            assert!(GE::deserialize(&bytes).is_err(), "{}", encoding);
+        }
+    }
+}
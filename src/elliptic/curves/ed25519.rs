@@ -97,6 +97,12 @@ impl ops::DerefMut for SK {
 ///   recovering x coordinate of ed25519 point from its y coordinate. Every time you call
 ///   `.x_coord()` or `from_coords()`, it takes y coordinate and runs `xrecover(y)` underhood. Keep
 ///   in mind that `xrecover` is quite expensive operation.
+/// * constant time
+///
+///   Multiplication of the generator at scalar is constant time. Multiplication of arbitrary
+///   point at scalar is implemented via variable time double-scalar multiplication provided by
+///   underlying library, so it shouldn't be used with secret scalars if timing side channels are
+///   a concern.
 #[derive(Debug, PartialEq, Clone)]
 pub enum Ed25519 {}
 
@@ -178,6 +184,12 @@ impl ECScalar for Ed25519Scalar {
         if bytes.len() != 32 {
             return Err(DeserializationError);
         }
+        // Scalar is encoded in little-endian, reject non-canonical encodings
+        let mut be = Zeroizing::new(bytes.to_vec());
+        be.reverse();
+        if &BigInt::from_bytes(&be) >= Self::group_order() {
+            return Err(DeserializationError);
+        }
         Ok(Ed25519Scalar {
             purpose: "deserialize",
             fe: SK(Fe::from_bytes(bytes)).into(),
@@ -397,25 +409,7 @@ impl ECPoint for Ed25519Point {
                     None => Err(DeserializationError),
                 }
             }
-            _ => {
-                let bytes_slice = &bytes_vec[0..32];
-                bytes_array_32.copy_from_slice(bytes_slice);
-                let ge_from_bytes = PK::from_bytes_negate_vartime(bytes);
-                match ge_from_bytes {
-                    Some(_x) => {
-                        let ge_bytes = ge_from_bytes.unwrap().to_bytes();
-                        let ge_from_bytes = PK::from_bytes_negate_vartime(&ge_bytes[..]);
-                        match ge_from_bytes {
-                            Some(y) => Ok(Ed25519Point {
-                                purpose: "random",
-                                ge: y,
-                            }),
-                            None => Err(DeserializationError),
-                        }
-                    }
-                    None => Err(DeserializationError),
-                }
-            }
+            _ => Err(DeserializationError),
         }
     }
 
@@ -438,6 +432,13 @@ impl ECPoint for Ed25519Point {
         }
     }
 
+    fn generator_mul(scalar: &Self::Scalar) -> Self {
+        Ed25519Point {
+            purpose: "generator_mul",
+            ge: ge_scalarmult_base(&scalar.fe.to_bytes()),
+        }
+    }
+
     fn add_point(&self, other: &Self) -> Ed25519Point {
         let pkpk = self.ge + other.ge.to_cached();
         let mut pk_p2_bytes = pkpk.to_p2().to_bytes();
@@ -97,7 +97,7 @@ pub use self::{
 };
 pub use self::{
     traits::{Curve, ECPoint, ECScalar, PointCoords},
-    wrappers::{EncodedPoint, EncodedScalar, Generator, Point, Scalar},
+    wrappers::{EncodedPoint, EncodedScalar, Generator, Point, Scalar, BINARY_FORMAT_VERSION},
 };
 
 pub mod error {
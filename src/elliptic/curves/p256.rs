@@ -8,7 +8,7 @@ use p256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
 use p256::{AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar};
 
 use generic_array::GenericArray;
-use rand::{thread_rng, Rng};
+use rand::{rngs::OsRng, Rng};
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroize;
 
@@ -90,7 +90,7 @@ impl ECScalar for Secp256r1Scalar {
     type ScalarLength = typenum::U32;
 
     fn random() -> Secp256r1Scalar {
-        let mut rng = thread_rng();
+        let mut rng = OsRng;
         let scalar = loop {
             let mut bytes = FieldBytes::default();
             rng.fill(&mut bytes[..]);
@@ -379,7 +379,7 @@ mod tests {
 
     use crate::arithmetic::*;
 
-    use super::{ECPoint, GE};
+    use super::{ECPoint, ECScalar, FE, GE};
 
     #[test]
     fn test_base_point2() {
@@ -405,4 +405,38 @@ mod tests {
             base_point2
         );
     }
+
+    #[test]
+    fn generator_multiplication_test_vectors() {
+        // (k, x, y) where (x, y) = k*G
+        let vectors = [
+            (
+                "1",
+                "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+                "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+            ),
+            (
+                "2",
+                "7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978",
+                "07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1",
+            ),
+            (
+                "3",
+                "5ecbe4d1a6330a44c8f7ef951d4bf165e6c6b721efada985fb41661bc6e7fd6c",
+                "8734640c4998ff7e374b06ce1a64a2ecd82ab036384fb83d9a79b127a27d5032",
+            ),
+            (
+                "18ebbb95eed0e13",
+                "339150844ec15234807fe862a86be77977dbfb3ae3d96f4c22795513aeaab82f",
+                "b1c14ddfdc8ec1b2583f51e85a5eb3a155840f2034730e9b5ada38b674336a21",
+            ),
+        ];
+        for (k, x, y) in vectors.iter() {
+            let k = FE::from_bigint(&BigInt::from_hex(k).unwrap());
+            let p = GE::generator_mul(&k);
+            assert_eq!(p.x_coord().unwrap(), BigInt::from_hex(x).unwrap());
+            assert_eq!(p.y_coord().unwrap(), BigInt::from_hex(y).unwrap());
+            assert_eq!(GE::generator().scalar_mul(&k), p);
+        }
+    }
 }
@@ -16,17 +16,20 @@
 // The Public Key codec: Point <> SecretKey
 //
 
+use std::fmt;
 use std::ops;
 use std::ops::Deref;
 use std::ptr;
 use std::sync::atomic;
 
 use generic_array::GenericArray;
-use secp256k1::constants::{
-    self, GENERATOR_X, GENERATOR_Y, SECRET_KEY_SIZE, UNCOMPRESSED_PUBLIC_KEY_SIZE,
-};
+use rand::{rngs::OsRng, RngCore};
+use secp256k1::constants::{self, GENERATOR_X, GENERATOR_Y, SECRET_KEY_SIZE};
 use secp256k1::{PublicKey, SecretKey, SECP256K1};
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "keccak")]
+use sha3::{Digest, Keccak256};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::{Zeroize, Zeroizing};
 
 use crate::arithmetic::*;
@@ -36,19 +39,21 @@ use super::traits::*;
 lazy_static::lazy_static! {
     static ref CURVE_ORDER: BigInt = BigInt::from_bytes(&constants::CURVE_ORDER);
 
-    static ref GENERATOR_UNCOMRESSED: [u8; 65] = {
-        let mut g = [0u8; 65];
+    static ref FIELD_PRIME: BigInt = BigInt::from_bytes(&constants::FIELD_SIZE);
+
+    static ref GENERATOR_UNCOMRESSED: [u8; Secp256k1Point::UNCOMPRESSED_PUBLIC_KEY_SIZE] = {
+        let mut g = [0u8; Secp256k1Point::UNCOMPRESSED_PUBLIC_KEY_SIZE];
         g[0] = 0x04;
-        g[1..33].copy_from_slice(&GENERATOR_X);
-        g[33..].copy_from_slice(&GENERATOR_Y);
+        g[1..1 + COOR_SIZE].copy_from_slice(&GENERATOR_X);
+        g[1 + COOR_SIZE..].copy_from_slice(&GENERATOR_Y);
         g
     };
 
-    static ref BASE_POINT2_UNCOMPRESSED: [u8; 65] = {
-        let mut g = [0u8; 65];
+    static ref BASE_POINT2_UNCOMPRESSED: [u8; Secp256k1Point::UNCOMPRESSED_PUBLIC_KEY_SIZE] = {
+        let mut g = [0u8; Secp256k1Point::UNCOMPRESSED_PUBLIC_KEY_SIZE];
         g[0] = 0x04;
-        g[1..33].copy_from_slice(&BASE_POINT2_X);
-        g[33..].copy_from_slice(&BASE_POINT2_Y);
+        g[1..1 + COOR_SIZE].copy_from_slice(&BASE_POINT2_X);
+        g[1 + COOR_SIZE..].copy_from_slice(&BASE_POINT2_Y);
         g
     };
 
@@ -63,6 +68,9 @@ lazy_static::lazy_static! {
     };
 }
 
+/// Size of a single coordinate in uncompressed point encoding `0x04 || x || y`
+const COOR_SIZE: usize = (Secp256k1Point::UNCOMPRESSED_PUBLIC_KEY_SIZE - 1) / 2;
+
 /* X coordinate of a point of unknown discrete logarithm.
 Computed using a deterministic algorithm with the generator as input.
 See test_base_point2 */
@@ -136,7 +144,11 @@ impl Curve for Secp256k1 {
     const CURVE_NAME: &'static str = "secp256k1";
 }
 
-#[derive(Clone, Debug)]
+/// Secp256k1 scalar
+///
+/// [Debug](fmt::Debug) implementation doesn't reveal the scalar value, use
+/// [expose_debug](Self::expose_debug) if you need it (e.g. in tests).
+#[derive(Clone)]
 pub struct Secp256k1Scalar {
     purpose: &'static str,
     /// Zeroizing<SK> wraps SK and zeroize it on drop
@@ -159,11 +171,7 @@ impl ECScalar for Secp256k1Scalar {
     type ScalarLength = typenum::U32;
 
     fn random() -> Secp256k1Scalar {
-        let sk = SK(SecretKey::new(&mut rand_legacy::thread_rng()));
-        Secp256k1Scalar {
-            purpose: "random",
-            fe: Zeroizing::new(Some(sk)),
-        }
+        Self::random_from_rng(&mut OsRng)
     }
 
     fn zero() -> Secp256k1Scalar {
@@ -308,12 +316,280 @@ impl ECScalar for Secp256k1Scalar {
     }
 }
 
+impl From<u32> for Secp256k1Scalar {
+    fn from(n: u32) -> Self {
+        Self::from(u64::from(n))
+    }
+}
+
+impl From<u64> for Secp256k1Scalar {
+    fn from(n: u64) -> Self {
+        if n == 0 {
+            return Self::zero();
+        }
+        let mut bytes = [0u8; SECRET_KEY_SIZE];
+        bytes[SECRET_KEY_SIZE - 8..].copy_from_slice(&n.to_be_bytes());
+        Secp256k1Scalar {
+            purpose: "from_u64",
+            fe: Zeroizing::new(Some(SK(
+                SecretKey::from_slice(&bytes).expect("nonzero u64 is always less than curve order")
+            ))),
+        }
+    }
+}
+
+impl fmt::Debug for Secp256k1Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // blind sensitive data stored by the structure
+        f.debug_struct("Secp256k1Scalar")
+            .field("purpose", &self.purpose)
+            .field("fe", &format_args!("***"))
+            .finish()
+    }
+}
+
 impl PartialEq for Secp256k1Scalar {
     fn eq(&self, other: &Secp256k1Scalar) -> bool {
         self.underlying_ref() == other.underlying_ref()
     }
 }
 
+impl Secp256k1Scalar {
+    /// Samples a scalar uniformly distributed in `[1, q)`
+    ///
+    /// Fills 32 bytes from `rng` and resamples while they encode zero or a value not less than
+    /// group order.
+    fn random_from_rng(rng: &mut impl RngCore) -> Secp256k1Scalar {
+        let mut bytes = Zeroizing::new([0u8; SECRET_KEY_SIZE]);
+        let sk = loop {
+            rng.fill_bytes(&mut *bytes);
+            // Rejects zero and values not less than group order
+            if let Ok(sk) = SecretKey::from_slice(&*bytes) {
+                break SK(sk);
+            }
+        };
+        Secp256k1Scalar {
+            purpose: "random",
+            fe: Zeroizing::new(Some(sk)),
+        }
+    }
+
+    /// Adds two scalars, also returns whether the sum wrapped around group order
+    ///
+    /// Returns `(self + other mod q, self + other >= q)`. Intended for debugging: the sum is
+    /// computed over [BigInt], so it's not constant time.
+    pub fn add_with_reduction(&self, other: &Self) -> (Self, bool) {
+        let sum = self.to_bigint() + other.to_bigint();
+        let reduced = &sum >= Self::group_order();
+        (Self::from_bigint(&sum), reduced)
+    }
+
+    /// Formats the scalar including its value as hex
+    ///
+    /// Unlike [Debug](fmt::Debug) output, reveals the scalar, so shouldn't be used for logging
+    /// secrets.
+    pub fn expose_debug(&self) -> String {
+        format!(
+            "Secp256k1Scalar {{ purpose: {:?}, fe: {} }}",
+            self.purpose,
+            hex::encode(self.serialize())
+        )
+    }
+
+    /// Checks whether the scalar is zero in constant time
+    ///
+    /// Unlike [is_zero](ECScalar::is_zero), compares all 32 bytes of serialized scalar with zero
+    /// without branching on their values, so it can be used to reject zero secret nonces or
+    /// challenges. Note that zero scalar is stored as `None` internally, so serializing it still
+    /// takes a different code path than serializing a nonzero scalar.
+    pub fn ct_is_zero(&self) -> Choice {
+        let mut bytes = Zeroizing::new([0u8; SECRET_KEY_SIZE]);
+        bytes.copy_from_slice(&self.serialize());
+        bytes.ct_eq(&[0u8; SECRET_KEY_SIZE])
+    }
+
+    /// Returns `a` if `choice` is `0`, or `b` if `choice` is `1`
+    ///
+    /// Selection is performed over serialized scalars without branching on `choice`.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let (a, b) = (a.serialize(), b.serialize());
+        let mut selected = Zeroizing::new([0u8; 32]);
+        for ((s, a), b) in selected.iter_mut().zip(a.iter()).zip(b.iter()) {
+            *s = u8::conditional_select(a, b, choice);
+        }
+        Self::deserialize(&selected[..]).expect("selected scalar is one of valid scalars")
+    }
+}
+
+impl Secp256k1Point {
+    /// Size of a point in compressed SEC1 form `0x02/0x03 || x`
+    pub const COMPRESSED_PUBLIC_KEY_SIZE: usize = constants::PUBLIC_KEY_SIZE;
+    /// Size of a point in uncompressed SEC1 form `0x04 || x || y`
+    pub const UNCOMPRESSED_PUBLIC_KEY_SIZE: usize = constants::UNCOMPRESSED_PUBLIC_KEY_SIZE;
+
+    /// Returns prime `p` of the base field over which the curve is defined
+    ///
+    /// `p = 2^256 - 2^32 - 977`. Not to be confused with [group order](ECScalar::group_order).
+    pub fn field_prime() -> &'static BigInt {
+        &FIELD_PRIME
+    }
+
+    /// Checks whether `(x, y)` satisfies the curve equation `y^2 = x^3 + 7 (mod p)`
+    ///
+    /// Evaluated directly over `BigInt`s, so it works independently of the secp256k1 crate.
+    /// Coordinates are expected to be reduced, i.e. lie in range `[0, p)`.
+    pub fn is_on_curve(x: &BigInt, y: &BigInt) -> bool {
+        let p = Self::field_prime();
+        let in_range = |c: &BigInt| &BigInt::zero() <= c && c < p;
+        if !in_range(x) || !in_range(y) {
+            return false;
+        }
+        let y2 = BigInt::mod_mul(y, y, p);
+        let x3 = BigInt::mod_pow(x, &BigInt::from(3), p);
+        y2 == BigInt::mod_add(&x3, &BigInt::from(7), p)
+    }
+
+    /// Decompresses a point from its x coordinate and parity of y coordinate
+    ///
+    /// Computes `y = sqrt(x^3 + 7) mod p` and picks the root which is odd if `y_is_odd` is set, or
+    /// even otherwise. As `p ≡ 3 (mod 4)`, square root is computed as `v^((p+1)/4)`. Returns error
+    /// if `x` is not in range `[0, p)` or there's no point with such x coordinate.
+    pub fn from_x_coord(x: &BigInt, y_is_odd: bool) -> Result<Self, NotOnCurve> {
+        let p = Self::field_prime();
+        if x < &BigInt::zero() || x >= p {
+            return Err(NotOnCurve);
+        }
+        let v = BigInt::mod_add(
+            &BigInt::mod_pow(x, &BigInt::from(3), p),
+            &BigInt::from(7),
+            p,
+        );
+        let mut y = BigInt::mod_pow(&v, &((p + 1) >> 2), p);
+        if BigInt::mod_mul(&y, &y, p) != v {
+            return Err(NotOnCurve);
+        }
+        if y.test_bit(0) != y_is_odd {
+            y = p - y;
+        }
+        Self::from_coords(x, &y)
+    }
+
+    /// Serializes the point in compressed form `0x02/0x03 || x`
+    ///
+    /// Zero point is encoded as 33 zero bytes.
+    pub fn compress(&self) -> [u8; Self::COMPRESSED_PUBLIC_KEY_SIZE] {
+        match self.ge {
+            None => [0; Self::COMPRESSED_PUBLIC_KEY_SIZE],
+            Some(ge) => ge.serialize(),
+        }
+    }
+
+    /// Serializes the point as specified in [SEC1](https://www.secg.org/sec1-v2.pdf) (section 2.3.3)
+    ///
+    /// Unlike [serialize_compressed](ECPoint::serialize_compressed) and
+    /// [serialize_uncompressed](ECPoint::serialize_uncompressed), zero point is encoded as a
+    /// single `0x00` byte, which is accepted by [deserialize](ECPoint::deserialize).
+    pub fn to_sec1_bytes(&self, compressed: bool) -> Vec<u8> {
+        match self.ge {
+            None => vec![0],
+            Some(ge) if compressed => ge.serialize().to_vec(),
+            Some(ge) => ge.serialize_uncompressed().to_vec(),
+        }
+    }
+
+    /// Computes `aG + bG` as `(a + b)G`
+    ///
+    /// Adds scalars first, so only one generator multiplication is performed instead of two.
+    pub fn combine_scalars_to_point(a: &Secp256k1Scalar, b: &Secp256k1Scalar) -> Self {
+        Self::generator_mul(&a.add(b))
+    }
+
+    /// Multiplies both points at the same scalar, returns `(a·g, a·h)`
+    ///
+    /// Useful for rotating blinding of a generator pair (e.g. `G` and [base_point2](ECPoint::base_point2))
+    /// at once. The scalar is unpacked only once for both multiplications.
+    pub fn scale_pair(g: &Self, h: &Self, a: &Secp256k1Scalar) -> (Self, Self) {
+        let mul = |point: &Self, fe: &SK| Secp256k1Point {
+            purpose: "scale_pair",
+            ge: point.ge.map(|mut ge| {
+                ge.0.mul_assign(SECP256K1, &fe.0[..])
+                    .expect("Can't fail as it's a valid secret");
+                ge
+            }),
+        };
+        match &*a.fe {
+            Some(fe) => (mul(g, fe), mul(h, fe)),
+            None => (Self::zero(), Self::zero()),
+        }
+    }
+
+    /// Checks whether two points are equal in constant time
+    ///
+    /// Compares [compressed](Self::compress) encodings without branching on their contents.
+    /// Intended for points derived from secret data (e.g. OPRF outputs). Note that serialization
+    /// itself is performed by libsecp256k1, and zero point takes a different code path.
+    pub fn ct_eq(&self, other: &Self) -> Choice {
+        self.compress().ct_eq(&other.compress())
+    }
+
+    /// Returns `a` if `choice` is `0`, or `b` if `choice` is `1`
+    ///
+    /// Selection is performed over compressed points without branching on `choice`, selected
+    /// point is parsed back afterwards.
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let (a, b) = (a.serialize_compressed(), b.serialize_compressed());
+        let mut selected = [0u8; Self::COMPRESSED_PUBLIC_KEY_SIZE];
+        for ((s, a), b) in selected.iter_mut().zip(a.iter()).zip(b.iter()) {
+            *s = u8::conditional_select(a, b, choice);
+        }
+        Self::deserialize(&selected).expect("selected point is one of valid points")
+    }
+
+    /// Derives Ethereum address of the public key
+    ///
+    /// Address is the last 20 bytes of Keccak256 hash of the uncompressed point without `0x04`
+    /// prefix.
+    ///
+    /// Address always has 20 bytes, so it's returned as an array rather than `Vec<u8>`. Zero point
+    /// has no uncompressed encoding, hence no address: `None` is returned instead of hashing some
+    /// placeholder bytes into an address nobody controls.
+    #[cfg(feature = "keccak")]
+    pub fn to_eth_address(&self) -> Option<[u8; 20]> {
+        let pk = self.ge?.serialize_uncompressed();
+        let hash = Keccak256::digest(&pk[1..]);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash[hash.len() - 20..]);
+        Some(address)
+    }
+
+    /// Parses a point in compressed form produced by [compress](Self::compress)
+    ///
+    /// Returns error if prefix is neither `0x02` nor `0x03` (unless it's an encoding of zero point),
+    /// or if `x` isn't a coordinate of any point on the curve.
+    pub fn decompress(
+        bytes: &[u8; Self::COMPRESSED_PUBLIC_KEY_SIZE],
+    ) -> Result<Self, DeserializationError> {
+        if bytes[0] != 0x02 && bytes[0] != 0x03 && bytes != &[0; Self::COMPRESSED_PUBLIC_KEY_SIZE] {
+            return Err(DeserializationError);
+        }
+        Self::deserialize(bytes)
+    }
+
+    /// Hashes an arbitrary message to a point with unknown logarithm
+    ///
+    /// Same as [hash_to_curve::hash_to_point], but returns a raw point.
+    pub fn hash_to_point(msg: &[u8]) -> Self {
+        hash_to_curve::hash_to_point(msg).into_raw()
+    }
+
+    /// Derives a "nothing-up-my-sleeve" generator for given `domain`
+    ///
+    /// Same as [hash_to_curve::nums_generator], but returns a raw point.
+    pub fn nums_generator(domain: &[u8]) -> Self {
+        hash_to_curve::nums_generator(domain).into_raw()
+    }
+}
+
 impl ECPoint for Secp256k1Point {
     type Scalar = Secp256k1Scalar;
     type Underlying = Option<PK>;
@@ -341,10 +617,12 @@ impl ECPoint for Secp256k1Point {
     }
 
     fn from_coords(x: &BigInt, y: &BigInt) -> Result<Self, NotOnCurve> {
+        if !Self::is_on_curve(x, y) {
+            return Err(NotOnCurve);
+        }
         let vec_x = x.to_bytes();
         let vec_y = y.to_bytes();
-        const COOR_SIZE: usize = (UNCOMPRESSED_PUBLIC_KEY_SIZE - 1) / 2;
-        let mut point = [0u8; UNCOMPRESSED_PUBLIC_KEY_SIZE];
+        let mut point = [0u8; Self::UNCOMPRESSED_PUBLIC_KEY_SIZE];
         point[0] = 0x04;
         point[1 + COOR_SIZE - vec_x.len()..1 + COOR_SIZE].copy_from_slice(&vec_x);
         point[1 + (2 * COOR_SIZE) - vec_y.len()..].copy_from_slice(&vec_y);
@@ -364,7 +642,7 @@ impl ECPoint for Secp256k1Point {
         match &self.ge {
             Some(ge) => {
                 let serialized_pk = ge.serialize_uncompressed();
-                let x = &serialized_pk[1..serialized_pk.len() / 2 + 1];
+                let x = &serialized_pk[1..1 + COOR_SIZE];
                 Some(BigInt::from_bytes(x))
             }
             None => None,
@@ -375,7 +653,7 @@ impl ECPoint for Secp256k1Point {
         match &self.ge {
             Some(ge) => {
                 let serialized_pk = ge.serialize_uncompressed();
-                let y = &serialized_pk[(serialized_pk.len() - 1) / 2 + 1..serialized_pk.len()];
+                let y = &serialized_pk[1 + COOR_SIZE..];
                 Some(BigInt::from_bytes(y))
             }
             None => None,
@@ -386,8 +664,8 @@ impl ECPoint for Secp256k1Point {
         match &self.ge {
             Some(ge) => {
                 let serialized_pk = ge.serialize_uncompressed();
-                let x = &serialized_pk[1..serialized_pk.len() / 2 + 1];
-                let y = &serialized_pk[(serialized_pk.len() - 1) / 2 + 1..serialized_pk.len()];
+                let x = &serialized_pk[1..1 + COOR_SIZE];
+                let y = &serialized_pk[1 + COOR_SIZE..];
                 Some(PointCoords {
                     x: BigInt::from_bytes(x),
                     y: BigInt::from_bytes(y),
@@ -399,20 +677,25 @@ impl ECPoint for Secp256k1Point {
 
     fn serialize_compressed(&self) -> GenericArray<u8, Self::CompressedPointLength> {
         match self.ge {
-            None => *GenericArray::from_slice(&[0u8; 33]),
+            None => *GenericArray::from_slice(&[0u8; Self::COMPRESSED_PUBLIC_KEY_SIZE]),
             Some(ge) => *GenericArray::from_slice(&ge.serialize()),
         }
     }
 
     fn serialize_uncompressed(&self) -> GenericArray<u8, Self::UncompressedPointLength> {
         match self.ge {
-            None => *GenericArray::from_slice(&[0u8; 65]),
+            None => *GenericArray::from_slice(&[0u8; Self::UNCOMPRESSED_PUBLIC_KEY_SIZE]),
             Some(ge) => *GenericArray::from_slice(&ge.serialize_uncompressed()),
         }
     }
 
     fn deserialize(bytes: &[u8]) -> Result<Secp256k1Point, DeserializationError> {
-        if bytes == [0; 33] || bytes == [0; 65] {
+        // Besides SEC1 encoding of zero point (single 0x00 byte), accept zero point in
+        // fixed-length encoding produced by `serialize_(un)compressed`
+        if bytes == [0]
+            || bytes == [0; Self::COMPRESSED_PUBLIC_KEY_SIZE]
+            || bytes == [0; Self::UNCOMPRESSED_PUBLIC_KEY_SIZE]
+        {
             Ok(Secp256k1Point {
                 purpose: "from_bytes",
                 ge: None,
@@ -515,22 +798,105 @@ impl Zeroize for Secp256k1Point {
 }
 
 pub mod hash_to_curve {
+    use sha2::{Digest, Sha256};
+
     use crate::elliptic::curves::wrappers::{Point, Scalar};
     use crate::{arithmetic::traits::*, BigInt};
 
-    use super::Secp256k1;
+    use super::{Secp256k1, Secp256k1Point};
+
+    /// Domain separation tag used by [hash_to_point]
+    pub const HASH_TO_POINT_DST: &[u8] = b"CURV-secp256k1-SHA256-TAI-hash-to-point";
+
+    /// Hashes an arbitrary message to secp256k1 point with unknown logarithm
+    ///
+    /// Uses try-and-increment method: for `ctr = 0, 1, 2, ...` it computes
+    /// `x = SHA256(DST || msg || ctr)` (`ctr` is encoded as 4 bytes big-endian, `DST` is
+    /// [HASH_TO_POINT_DST]) and returns the first point with x coordinate `x` and even y
+    /// coordinate. Every attempt succeeds with probability ~1/2, so the output is always a valid
+    /// point. Note that the method is not constant time, so `msg` shouldn't be secret.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use curv::elliptic::curves::secp256_k1::hash_to_curve::hash_to_point;
+    ///
+    /// let p1 = hash_to_point(b"message 1");
+    /// let p2 = hash_to_point(b"message 2");
+    /// assert_ne!(p1, p2);
+    /// assert_eq!(p1, hash_to_point(b"message 1"));
+    /// ```
+    pub fn hash_to_point(msg: &[u8]) -> Point<Secp256k1> {
+        hash_to_point_with_dst(HASH_TO_POINT_DST, msg)
+    }
+
+    /// Domain separation tag used by [nums_generator]
+    pub const NUMS_GENERATOR_DST: &[u8] = b"CURV-secp256k1-SHA256-TAI-nums-generator";
+
+    /// Derives a "nothing-up-my-sleeve" generator for given `domain`
+    ///
+    /// Generalizes [base_point2](crate::elliptic::curves::ECPoint::base_point2): protocols running
+    /// side-by-side can use their own domain tags to get independent generators with unknown
+    /// discrete logarithms. Computed the same way as [hash_to_point], but with
+    /// [NUMS_GENERATOR_DST] as domain separation tag, so it never collides with hashed messages.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use curv::elliptic::curves::secp256_k1::hash_to_curve::nums_generator;
+    ///
+    /// let h1 = nums_generator(b"protocol A");
+    /// let h2 = nums_generator(b"protocol B");
+    /// assert_ne!(h1, h2);
+    /// ```
+    pub fn nums_generator(domain: &[u8]) -> Point<Secp256k1> {
+        hash_to_point_with_dst(NUMS_GENERATOR_DST, domain)
+    }
+
+    fn hash_to_point_with_dst(dst: &[u8], msg: &[u8]) -> Point<Secp256k1> {
+        let hasher = Sha256::new().chain(dst).chain(msg);
+        for ctr in 0u32.. {
+            let x = hasher.clone().chain(ctr.to_be_bytes()).finalize();
+            let mut buffer = [0u8; Secp256k1Point::COMPRESSED_PUBLIC_KEY_SIZE];
+            buffer[0] = 0x2;
+            buffer[1..].copy_from_slice(&x);
+            if let Ok(point) = Point::from_bytes(&buffer) {
+                return point;
+            }
+        }
+        unreachable!("the probability of reaching this is negligible (2^-(2^32))")
+    }
+
+    /// Domain separation tag used by [generate_generators]
+    pub const GENERATORS_DST: &[u8] = b"CURV-secp256k1-SHA256-TAI-generators";
+
+    /// Deterministically derives vectors of independent generators `G_1..G_n` and `H_1..H_n`
+    ///
+    /// Intended for vector commitments (e.g. Bulletproofs inner-product argument). `G_i` and `H_i`
+    /// are computed the same way as [hash_to_point] of `"G" || i` and `"H" || i` respectively
+    /// (`i` is encoded as 8 bytes big-endian), but with [GENERATORS_DST] as domain separation tag,
+    /// so nobody knows discrete logarithms between any of them, and hashed messages never collide
+    /// with the generators.
+    pub fn generate_generators(n: usize) -> (Vec<Point<Secp256k1>>, Vec<Point<Secp256k1>>) {
+        let generators = |label: &[u8]| {
+            (0..n as u64)
+                .map(|i| {
+                    hash_to_point_with_dst(GENERATORS_DST, &[label, &i.to_be_bytes()].concat())
+                })
+                .collect()
+        };
+        (generators(b"G"), generators(b"H"))
+    }
 
     /// Takes uniformly distributed bytes and produces secp256k1 point with unknown logarithm
     ///
     /// __Note:__ this function is subject to change
     pub fn generate_random_point(bytes: &[u8]) -> Point<Secp256k1> {
-        let compressed_point_len = secp256k1::constants::PUBLIC_KEY_SIZE;
+        let compressed_point_len = Secp256k1Point::COMPRESSED_PUBLIC_KEY_SIZE;
         let truncated = if bytes.len() > compressed_point_len - 1 {
             &bytes[0..compressed_point_len - 1]
         } else {
             &bytes
         };
-        let mut buffer = [0u8; secp256k1::constants::PUBLIC_KEY_SIZE];
+        let mut buffer = [0u8; Secp256k1Point::COMPRESSED_PUBLIC_KEY_SIZE];
         buffer[0] = 0x2;
         buffer[1..1 + truncated.len()].copy_from_slice(truncated);
         if let Ok(point) = Point::from_bytes(&buffer) {
@@ -546,7 +912,8 @@ pub mod hash_to_curve {
 
     #[cfg(test)]
     mod tests {
-        use super::generate_random_point;
+        use super::{generate_generators, generate_random_point, hash_to_point, nums_generator};
+        use crate::elliptic::curves::wrappers::Point;
 
         #[test]
         fn generates_point() {
@@ -560,16 +927,91 @@ pub mod hash_to_curve {
             let point2 = generate_random_point(&[2u8; 32]);
             assert_ne!(point1, point2)
         }
+
+        #[test]
+        fn hashes_distinct_messages_to_distinct_points() {
+            let messages: [&[u8]; 4] = [b"", b"a", b"b", &[0u8; 100]];
+            let points = messages
+                .iter()
+                .map(|msg| hash_to_point(msg))
+                .collect::<Vec<_>>();
+            for (i, p) in points.iter().enumerate() {
+                assert!(!p.is_zero());
+                let coords = p.coords().unwrap();
+                assert_eq!(&Point::from_coords(&coords.x, &coords.y).unwrap(), p);
+                assert_eq!(p, &hash_to_point(messages[i]));
+                for q in &points[i + 1..] {
+                    assert_ne!(p, q);
+                }
+            }
+        }
+
+        #[test]
+        fn nums_generators_are_distinct_per_domain() {
+            let h1 = nums_generator(b"domain 1");
+            let h2 = nums_generator(b"domain 2");
+            assert_ne!(h1, h2);
+            for h in [&h1, &h2] {
+                let coords = h.coords().unwrap();
+                assert_eq!(&Point::from_coords(&coords.x, &coords.y).unwrap(), h);
+            }
+            assert_ne!(h1, hash_to_point(b"domain 1"));
+
+            // Output must be stable across runs and versions
+            assert_eq!(h1, nums_generator(b"domain 1"));
+            assert_eq!(
+                hex::encode(&*h1.to_bytes(true)),
+                "029e7871d688bfbc2d040d7ea3f4c529ed76d8620e7f473f327efbb4424be8138f"
+            );
+        }
+
+        #[test]
+        fn generates_distinct_reproducible_generators() {
+            let (g, h) = generate_generators(8);
+            assert_eq!((g.len(), h.len()), (8, 8));
+            assert_eq!((g.clone(), h.clone()), generate_generators(8));
+            assert_eq!(&g[..4], &generate_generators(4).0[..]);
+
+            let all = g.iter().chain(&h).collect::<Vec<_>>();
+            for (i, p) in all.iter().enumerate() {
+                assert!(!p.is_zero());
+                assert_ne!(*p, &Point::generator().to_point());
+                for q in &all[i + 1..] {
+                    assert_ne!(p, q);
+                }
+            }
+            // generators are separated from hashed messages
+            assert_ne!(g[0], hash_to_point(b"G\0\0\0\0\0\0\0\0"));
+
+            let expected_g = [
+                "02fc2f09c1fd7f475db60b623c1fa7f48cb31b5a403fe0e3033bdcbc0803ae6c37",
+                "0255854422f478e1dd13ce7682ecc1a1172b1c5db37aa10b646b3c2f892d17291a",
+            ];
+            let expected_h = [
+                "02165c324c5544e7c450d04621713b4ced2e298a70de54cf215194e78fb5bcc3da",
+                "026fdd003daa63d463a1e32de6561cedb5f58ff2aa7cfc2f8db5104c47144ab014",
+            ];
+            for (p, expected) in g.iter().zip(&expected_g) {
+                assert_eq!(&p.to_hex(), expected);
+            }
+            for (p, expected) in h.iter().zip(&expected_h) {
+                assert_eq!(&p.to_hex(), expected);
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod test {
+    use secp256k1::constants::{GENERATOR_X, GENERATOR_Y};
+    use secp256k1::SecretKey;
     use sha2::{Digest, Sha256};
+    use subtle::Choice;
+    use zeroize::Zeroize;
 
     use crate::arithmetic::*;
 
-    use super::{ECPoint, GE};
+    use super::{ECPoint, ECScalar, FE, GE, SK};
 
     #[test]
     fn test_base_point2() {
@@ -596,4 +1038,441 @@ mod test {
             base_point2
         );
     }
+
+    #[test]
+    fn uncompressed_serialization_is_prefix_x_y() {
+        let p = GE::generator().scalar_mul(&FE::random());
+        let bytes = p.serialize_uncompressed();
+        let coords = p.coords().unwrap();
+
+        assert_eq!(bytes[0], 0x04);
+        assert_eq!(&bytes[1..33], &coords.x.to_bytes_array::<32>().unwrap()[..]);
+        assert_eq!(
+            &bytes[33..65],
+            &coords.y.to_bytes_array::<32>().unwrap()[..]
+        );
+
+        let x = BigInt::from_bytes(&bytes[1..33]);
+        let y = BigInt::from_bytes(&bytes[33..65]);
+        assert_eq!(GE::from_coords(&x, &y).unwrap(), p);
+    }
+
+    #[test]
+    fn deserialize_sec1_test_vectors() {
+        // (k, compressed k*G, uncompressed k*G)
+        let vectors = [
+            (
+                1,
+                "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\
+                 483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+            ),
+            (
+                2,
+                "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+                "04c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5\
+                 1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+            ),
+            (
+                3,
+                "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+                "04f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9\
+                 388f7b0f632de8140fe337e62a37f3566500a99934c2231b6cb9fd7584b8e672",
+            ),
+        ];
+        for (k, compressed, uncompressed) in vectors.iter() {
+            let expected = GE::generator().scalar_mul(&FE::from_bigint(&BigInt::from(*k)));
+
+            let compressed = hex::decode(compressed).unwrap();
+            let p = GE::deserialize(&compressed).unwrap();
+            assert_eq!(p, expected);
+            assert_eq!(&p.serialize_compressed()[..], &compressed[..]);
+
+            let uncompressed = hex::decode(uncompressed).unwrap();
+            let p = GE::deserialize(&uncompressed).unwrap();
+            assert_eq!(p, expected);
+            assert_eq!(&p.serialize_uncompressed()[..], &uncompressed[..]);
+        }
+    }
+
+    #[test]
+    fn zeroize_overwrites_secret_key_bytes() {
+        let mut sk = SK(SecretKey::new(&mut rand_legacy::thread_rng()));
+        assert_ne!(&sk.0[..], &[0u8; 32][..]);
+        sk.zeroize();
+        assert_eq!(&sk.0[..], &[0u8; 32][..]);
+    }
+
+    #[test]
+    fn cached_generator_equals_freshly_computed_one() {
+        let fresh = GE::from_coords(
+            &BigInt::from_bytes(&GENERATOR_X),
+            &BigInt::from_bytes(&GENERATOR_Y),
+        )
+        .unwrap();
+        assert_eq!(GE::generator(), &fresh);
+        assert!(std::ptr::eq(GE::generator(), GE::generator()));
+    }
+
+    #[test]
+    fn group_order_equals_known_value() {
+        let expected =
+            BigInt::from_hex("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141")
+                .unwrap();
+        assert_eq!(FE::group_order(), &expected);
+    }
+
+    #[test]
+    fn scalar_serialization_is_left_padded() {
+        let s = FE::from_bigint(&BigInt::from(123456));
+        let mut expected = [0u8; 32];
+        expected[29..].copy_from_slice(&[0x01, 0xe2, 0x40]);
+        assert_eq!(&s.serialize()[..], &expected[..]);
+        assert_eq!(FE::deserialize(&expected).unwrap(), s);
+        assert!(FE::deserialize(&expected[1..]).is_err());
+    }
+
+    #[test]
+    fn conditional_select_picks_scalar_by_choice() {
+        let a = FE::random();
+        let b = FE::random();
+        for (a, b) in [(&a, &b), (&a, &FE::zero()), (&FE::zero(), &b)] {
+            assert_eq!(&FE::conditional_select(a, b, Choice::from(0)), a);
+            assert_eq!(&FE::conditional_select(a, b, Choice::from(1)), b);
+        }
+    }
+
+    #[test]
+    fn conditional_select_picks_point_by_choice() {
+        let a = GE::generator().scalar_mul(&FE::random());
+        let b = GE::generator().scalar_mul(&FE::random());
+        for (a, b) in [(&a, &b), (&a, &GE::zero()), (&GE::zero(), &b)] {
+            assert_eq!(&GE::conditional_select(a, b, Choice::from(0)), a);
+            assert_eq!(&GE::conditional_select(a, b, Choice::from(1)), b);
+        }
+    }
+
+    #[test]
+    fn equality_ignores_purpose() {
+        let q = GE::generator().scalar_mul(&FE::random());
+        let q_from_bytes = GE::deserialize(&q.serialize_compressed()).unwrap();
+        let g_from_coords = GE::from_coords(
+            &BigInt::from_bytes(&GENERATOR_X),
+            &BigInt::from_bytes(&GENERATOR_Y),
+        )
+        .unwrap();
+        assert_eq!(q, q_from_bytes);
+        assert_eq!(
+            GE::generator().add_point(&q_from_bytes),
+            q.add_point(&g_from_coords)
+        );
+
+        let s = FE::random();
+        let s_from_bytes = FE::deserialize(&s.serialize()).unwrap();
+        let s_from_bigint = FE::from_bigint(&s.to_bigint());
+        assert_eq!(s_from_bytes, s_from_bigint);
+        assert_eq!(s.add(&FE::zero()), s_from_bytes);
+    }
+
+    #[test]
+    fn is_on_curve_test() {
+        let x = BigInt::from_bytes(&GENERATOR_X);
+        let y = BigInt::from_bytes(&GENERATOR_Y);
+        assert!(GE::is_on_curve(&x, &y));
+
+        let q = GE::generator().scalar_mul(&FE::random());
+        assert!(GE::is_on_curve(
+            &q.x_coord().unwrap(),
+            &q.y_coord().unwrap()
+        ));
+
+        assert!(!GE::is_on_curve(&x, &(&y + 1)));
+        assert!(!GE::is_on_curve(&(&x + 1), &y));
+        assert!(!GE::is_on_curve(&BigInt::zero(), &BigInt::zero()));
+
+        // Coordinates out of field range are rejected rather than reduced
+        let p = GE::field_prime();
+        assert!(!GE::is_on_curve(&(&x + p), &y));
+        assert!(GE::from_coords(&(&x + p), &y).is_err());
+        assert!(GE::from_coords(&(x << 256), &y).is_err());
+    }
+
+    #[test]
+    fn field_prime_equals_known_value() {
+        let expected =
+            BigInt::from_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")
+                .unwrap();
+        assert_eq!(GE::field_prime(), &expected);
+        assert_eq!(
+            &expected,
+            &(BigInt::from(2).pow(256) - BigInt::from(2).pow(32) - 977)
+        );
+        assert_ne!(GE::field_prime(), FE::group_order());
+    }
+
+    #[test]
+    fn from_x_coord_decompresses_generator() {
+        let x = BigInt::from_bytes(&GENERATOR_X);
+        let g = GE::from_x_coord(&x, false).unwrap();
+        assert_eq!(&g, GE::generator());
+        let minus_g = GE::from_x_coord(&x, true).unwrap();
+        assert_eq!(minus_g, GE::generator().neg_point());
+    }
+
+    #[test]
+    fn from_x_coord_matches_compressed_deserialization() {
+        for x in 0u32..20 {
+            let x = BigInt::from(x);
+            for (y_is_odd, prefix) in [(false, 0x02), (true, 0x03)] {
+                let mut compressed = [0u8; 33];
+                compressed[0] = prefix;
+                compressed[33 - x.to_bytes().len()..].copy_from_slice(&x.to_bytes());
+                let expected = GE::deserialize(&compressed).ok();
+                assert_eq!(GE::from_x_coord(&x, y_is_odd).ok(), expected);
+            }
+        }
+        assert!(GE::from_x_coord(GE::field_prime(), false).is_err());
+        assert!(GE::from_x_coord(&BigInt::from(-1), false).is_err());
+    }
+
+    #[test]
+    fn generator_coords_match_known_constants() {
+        let g = GE::generator();
+        let x = BigInt::from_bytes(&GENERATOR_X);
+        let y = BigInt::from_bytes(&GENERATOR_Y);
+        assert_eq!(g.x_coord().unwrap(), x);
+        assert_eq!(g.y_coord().unwrap(), y);
+        let coords = g.coords().unwrap();
+        assert_eq!((coords.x, coords.y), (x, y));
+        assert_eq!(
+            GE::generator().x_coord().unwrap().to_hex(),
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798"
+        );
+        assert_eq!(
+            GE::generator().y_coord().unwrap().to_hex(),
+            "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+        );
+        assert!(GE::zero().coords().is_none());
+    }
+
+    #[test]
+    fn from_u64_matches_from_bigint() {
+        for n in [0u64, 1, 5, (1 << 63) - 1, 1 << 63, u64::MAX] {
+            assert_eq!(FE::from(n), FE::from_bigint(&BigInt::from(n)), "{}", n);
+        }
+        for n in [0u32, 1, u32::MAX] {
+            assert_eq!(FE::from(n), FE::from_bigint(&BigInt::from(n)), "{}", n);
+        }
+        assert!(FE::from(0u64).is_zero());
+        assert_eq!(FE::from(1u64).to_bigint(), BigInt::one());
+    }
+
+    #[test]
+    fn compress_decompress_round_trip() {
+        for _ in 0..20 {
+            let p = GE::generator_mul(&FE::random());
+            assert_eq!(GE::decompress(&p.compress()).unwrap(), p);
+        }
+        assert!(GE::decompress(&GE::zero().compress()).unwrap().is_zero());
+        assert_eq!(&GE::generator().compress()[1..], &GENERATOR_X[..]);
+
+        let mut bytes = GE::generator().compress();
+        for prefix in [0x00, 0x04, 0x05, 0xff] {
+            bytes[0] = prefix;
+            assert!(GE::decompress(&bytes).is_err());
+        }
+        // x = 5 is not a coordinate of any point: 5^3 + 7 is not a square modulo p
+        let mut off_curve = [0u8; 33];
+        off_curve[0] = 0x02;
+        off_curve[32] = 5;
+        assert!(GE::decompress(&off_curve).is_err());
+        // x = p is out of range
+        let mut out_of_range = [0u8; 33];
+        out_of_range[0] = 0x02;
+        out_of_range[1..].copy_from_slice(&GE::field_prime().to_bytes());
+        assert!(GE::decompress(&out_of_range).is_err());
+    }
+
+    #[test]
+    fn public_key_size_constants_match_serialized_lengths() {
+        assert_eq!(GE::COMPRESSED_PUBLIC_KEY_SIZE, 33);
+        assert_eq!(GE::UNCOMPRESSED_PUBLIC_KEY_SIZE, 65);
+        for p in [GE::zero(), GE::generator_mul(&FE::random())] {
+            assert_eq!(
+                p.serialize_compressed().len(),
+                GE::COMPRESSED_PUBLIC_KEY_SIZE
+            );
+            assert_eq!(
+                p.serialize_uncompressed().len(),
+                GE::UNCOMPRESSED_PUBLIC_KEY_SIZE
+            );
+            assert_eq!(p.compress().len(), GE::COMPRESSED_PUBLIC_KEY_SIZE);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "keccak")]
+    fn eth_address_test_vectors() {
+        // (private key, address)
+        let vectors = [
+            (1u64, "7e5f4552091a69125d5dfcb7b8c2659029395bdf"),
+            (2, "2b5ad5c4795c026514f8317c7a215e218dccd6cf"),
+            (3, "6813eb9362372eef6200f3b1dbc3f819671cba69"),
+        ];
+        for (sk, address) in vectors.iter() {
+            let pk = GE::generator_mul(&FE::from(*sk));
+            assert_eq!(hex::encode(pk.to_eth_address().unwrap()), *address);
+        }
+        assert!(GE::zero().to_eth_address().is_none());
+    }
+
+    #[test]
+    fn add_with_reduction() {
+        let q = FE::group_order();
+        let a = FE::from_bigint(&(q - 1));
+        let b = FE::from_bigint(&(q - 5));
+        let (sum, reduced) = a.add_with_reduction(&b);
+        assert!(reduced);
+        assert_eq!(sum, a.add(&b));
+        assert_eq!(sum.to_bigint(), q - 6);
+
+        let (sum, reduced) = FE::from(2u64).add_with_reduction(&FE::from(3u64));
+        assert!(!reduced);
+        assert_eq!(sum, FE::from(5u64));
+
+        // q-1 + 1 = q is reduced to zero
+        let (sum, reduced) = a.add_with_reduction(&FE::from(1u64));
+        assert!(reduced);
+        assert!(sum.is_zero());
+    }
+
+    #[test]
+    fn debug_doesnt_leak_scalar() {
+        let scalar = FE::random();
+        let secret = hex::encode(scalar.serialize());
+        let debug = format!("{:?}", scalar);
+        assert!(!debug.contains(&secret), "{}", debug);
+        assert!(debug.contains("***"));
+        let wrapped = crate::elliptic::curves::Scalar::<super::Secp256k1>::from_raw(scalar.clone());
+        assert!(!format!("{:?}", wrapped).contains(&secret));
+
+        assert!(scalar.expose_debug().contains(&secret));
+    }
+
+    #[test]
+    fn ct_is_zero() {
+        assert!(bool::from(FE::from_bigint(&BigInt::from(0)).ct_is_zero()));
+        assert!(bool::from(FE::zero().ct_is_zero()));
+        assert!(bool::from(FE::from_bigint(FE::group_order()).ct_is_zero()));
+        assert!(!bool::from(FE::random().ct_is_zero()));
+        assert!(!bool::from(FE::from(1u64).ct_is_zero()));
+    }
+
+    #[test]
+    fn sec1_point_at_infinity() {
+        let zero = GE::deserialize(&[0]).unwrap();
+        assert!(zero.is_zero());
+        assert_eq!(zero.to_sec1_bytes(true), [0]);
+        assert_eq!(zero.to_sec1_bytes(false), [0]);
+        assert!(
+            crate::elliptic::curves::Point::<super::Secp256k1>::from_bytes(&[0])
+                .unwrap()
+                .is_zero()
+        );
+
+        let point = GE::generator_mul(&FE::random());
+        for compressed in [true, false] {
+            let bytes = point.to_sec1_bytes(compressed);
+            assert_eq!(GE::deserialize(&bytes).unwrap(), point);
+        }
+        assert!(GE::deserialize(&[1]).is_err());
+    }
+
+    #[test]
+    fn combine_scalars_to_point_matches_two_multiplications() {
+        let a = FE::random();
+        let b = FE::random();
+        let expected = GE::generator_mul(&a).add_point(&GE::generator_mul(&b));
+        assert_eq!(GE::combine_scalars_to_point(&a, &b), expected);
+
+        // a + (-a) = 0 gives point at infinity
+        assert!(GE::combine_scalars_to_point(&a, &a.neg()).is_zero());
+    }
+
+    #[test]
+    fn scale_pair_matches_scalar_mul() {
+        let g = GE::generator();
+        let h = GE::base_point2();
+        let a = FE::random();
+        assert_eq!(
+            GE::scale_pair(g, h, &a),
+            (g.scalar_mul(&a), h.scalar_mul(&a))
+        );
+        assert_eq!(
+            GE::scale_pair(g, &GE::zero(), &a),
+            (g.scalar_mul(&a), GE::zero())
+        );
+        assert_eq!(GE::scale_pair(g, h, &FE::zero()), (GE::zero(), GE::zero()));
+    }
+
+    #[test]
+    fn point_ct_eq_agrees_with_eq() {
+        let p1 = GE::generator_mul(&FE::random());
+        let p2 = GE::generator_mul(&FE::random());
+        let points = [p1, p2, p1.neg_point(), GE::zero(), *GE::generator()];
+        for a in &points {
+            for b in &points {
+                assert_eq!(bool::from(a.ct_eq(b)), a == b);
+            }
+        }
+        assert!(bool::from(
+            p1.ct_eq(&GE::deserialize(&p1.compress()).unwrap())
+        ));
+    }
+
+    #[test]
+    fn hash_points_to_scalar_is_deterministic() {
+        let p1 = GE::generator_mul(&FE::random());
+        let p2 = GE::generator_mul(&FE::random());
+
+        let h = GE::hash_points_to_scalar(&[&p1, &p2]);
+        assert_eq!(h, GE::hash_points_to_scalar(&[&p1, &p2]));
+        assert!(&h.to_bigint() < FE::group_order());
+        assert_ne!(h, GE::hash_points_to_scalar(&[&p2, &p1]));
+        assert_ne!(h, GE::hash_points_to_scalar(&[&p1]));
+        assert_ne!(
+            GE::hash_points_to_scalar(&[]),
+            GE::hash_points_to_scalar(&[&GE::zero()])
+        );
+    }
+
+    #[test]
+    fn hash_to_point_associated_fn_matches_module_fn() {
+        let point = GE::hash_to_point(b"message");
+        assert_eq!(
+            point,
+            super::hash_to_curve::hash_to_point(b"message").into_raw()
+        );
+        assert!(!point.is_zero());
+
+        assert_eq!(
+            GE::nums_generator(b"domain"),
+            super::hash_to_curve::nums_generator(b"domain").into_raw()
+        );
+    }
+
+    #[test]
+    fn random_scalar_resamples_zero_and_out_of_range_buffers() {
+        use crate::elliptic::curves::test::FixedRng;
+
+        let mut q = [0u8; 32];
+        q.copy_from_slice(&super::constants::CURVE_ORDER);
+        let mut seven = [0u8; 32];
+        seven[31] = 7;
+        let mut rng = FixedRng(vec![[0u8; 32], q, [0xff; 32], seven]);
+
+        let s = FE::random_from_rng(&mut rng);
+        assert_eq!(s, FE::from(7u64));
+        assert!(rng.0.is_empty());
+    }
 }
@@ -3,11 +3,13 @@
 use std::iter;
 
 use rand::{rngs::OsRng, Rng};
+use typenum::Unsigned;
 
 use crate::arithmetic::*;
 use crate::test_for_all_curves;
 
 use super::traits::*;
+use super::{Point, Scalar};
 
 fn random_nonzero_scalar<S: ECScalar>() -> S {
     loop {
@@ -104,6 +106,186 @@ fn zero_point_serialization<E: Curve>() {
     assert_eq!(point, point_from_uncompressed);
 }
 
+test_for_all_curves!(point_hex_round_trip);
+fn point_hex_round_trip<E: Curve>() {
+    let random_point = Point::<E>::generator() * Scalar::random();
+    for point in [Point::zero(), random_point] {
+        let hex = point.to_hex();
+        assert_eq!(hex, hex::encode(&*point.to_bytes(true)));
+        assert_eq!(Point::<E>::from_hex(&hex).unwrap(), point);
+    }
+
+    assert!(Point::<E>::from_hex("zz").is_err());
+    assert!(Point::<E>::from_hex("abc").is_err());
+    assert!(Point::<E>::from_hex(&"ff".repeat(200)).is_err());
+}
+
+test_for_all_curves!(display_from_str_round_trip);
+fn display_from_str_round_trip<E: Curve>() {
+    let random_point = Point::<E>::generator() * Scalar::random();
+    for point in [Point::zero(), random_point] {
+        let s = point.to_string();
+        assert_eq!(s, point.to_hex());
+        assert_eq!(s.parse::<Point<E>>().unwrap(), point);
+    }
+
+    for scalar in [Scalar::<E>::zero(), Scalar::from(1), Scalar::random()] {
+        let s = scalar.to_string();
+        assert_eq!(s, hex::encode(&*scalar.to_bytes()));
+        assert_eq!(s.parse::<Scalar<E>>().unwrap(), scalar);
+    }
+
+    assert!("zz".parse::<Point<E>>().is_err());
+    assert!("zz".parse::<Scalar<E>>().is_err());
+    assert!("00".parse::<Scalar<E>>().is_err());
+}
+
+test_for_all_curves!(random_scalar_with_seeded_rng_is_reproducible);
+fn random_scalar_with_seeded_rng_is_reproducible<E: Curve>() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let mut rng1 = StdRng::seed_from_u64(1);
+    let mut rng2 = StdRng::seed_from_u64(1);
+    for _ in 0..10 {
+        let s1 = Scalar::<E>::random_with_rng(&mut rng1);
+        let s2 = Scalar::<E>::random_with_rng(&mut rng2);
+        assert_eq!(s1, s2);
+        assert!(!s1.is_zero());
+    }
+
+    let s1 = Scalar::<E>::random_with_rng(&mut StdRng::seed_from_u64(1));
+    let s2 = Scalar::<E>::random_with_rng(&mut StdRng::seed_from_u64(2));
+    assert_ne!(s1, s2);
+}
+
+test_for_all_curves!(key_generation_in_fresh_thread);
+fn key_generation_in_fresh_thread<E: Curve>() {
+    // Secret generation relies on system entropy, not on a thread-local RNG
+    let generated = std::thread::spawn(|| {
+        let sk = Scalar::<E>::random();
+        let pk = Point::generator() * &sk;
+        let sks = Scalar::<E>::random_vec(3);
+        !sk.is_zero() && !pk.is_zero() && sks.iter().all(|s| !s.is_zero())
+    })
+    .join()
+    .unwrap();
+    assert!(generated);
+}
+
+test_for_all_curves!(random_scalar_vec);
+fn random_scalar_vec<E: Curve>() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let scalars = Scalar::<E>::random_vec(20);
+    assert_eq!(scalars.len(), 20);
+    for (i, s) in scalars.iter().enumerate() {
+        assert!(!s.is_zero());
+        assert!(scalars[i + 1..].iter().all(|s2| s != s2));
+    }
+    assert!(Scalar::<E>::random_vec(0).is_empty());
+
+    let s1 = Scalar::<E>::random_vec_with_rng(5, &mut StdRng::seed_from_u64(1));
+    let s2 = Scalar::<E>::random_vec_with_rng(5, &mut StdRng::seed_from_u64(1));
+    assert_eq!(s1, s2);
+}
+
+test_for_all_curves!(scalar_from_bytes_rejects_non_canonical);
+fn scalar_from_bytes_rejects_non_canonical<E: Curve>() {
+    let q = Scalar::<E>::group_order();
+    let len = Scalar::<E>::zero().to_bytes().len();
+    let little_endian = Scalar::<E>::from(1).to_bytes()[0] == 1;
+    let encode = |n: &BigInt| {
+        let mut bytes = n.to_bytes();
+        bytes.reverse();
+        bytes.resize(len, 0);
+        if !little_endian {
+            bytes.reverse();
+        }
+        bytes
+    };
+
+    let q_minus_1 = Scalar::<E>::from_bytes(&encode(&(q - 1))).unwrap();
+    assert_eq!(q_minus_1, -Scalar::<E>::from(1));
+    assert!(q_minus_1.is_valid());
+    assert!(Scalar::<E>::from_bytes(&encode(q)).is_err());
+    assert!(Scalar::<E>::from_bytes(&encode(&(q + 1))).is_err());
+
+    assert!(!Scalar::<E>::zero().is_valid());
+    assert!(Scalar::<E>::random().is_valid());
+}
+
+test_for_all_curves!(scalar_mul_small);
+fn scalar_mul_small<E: Curve>() {
+    let s = Scalar::<E>::random();
+    assert_eq!(s.mul_small(2).as_raw(), &s.as_raw().add(s.as_raw()));
+    assert!(s.mul_small(0).is_zero());
+    assert_eq!(s.mul_small(1), s);
+    for n in [3, 7, 255, 256, 1 << 40, u64::MAX] {
+        assert_eq!(s.mul_small(n), &s * Scalar::from_bigint(&BigInt::from(n)));
+    }
+}
+
+test_for_all_curves!(scalar_little_endian_bytes);
+fn scalar_little_endian_bytes<E: Curve>() {
+    let scalar = Scalar::<E>::random();
+    let le = scalar.to_bytes_le();
+    assert_eq!(le.len(), scalar.to_bytes().len());
+    assert_eq!(
+        BigInt::from_bytes(&le.iter().rev().copied().collect::<Vec<_>>()),
+        scalar.to_bigint()
+    );
+    assert_eq!(Scalar::<E>::from_bytes_le(&le).unwrap(), scalar);
+
+    assert_eq!(Scalar::<E>::from(1).to_bytes_le()[0], 1);
+    assert!(Scalar::<E>::from_bytes_le(&le[1..]).is_err());
+    assert!(Scalar::<E>::from_bytes_le(&vec![0xff; le.len()]).is_err());
+}
+
+#[test]
+fn secp256k1_scalar_little_endian_is_reversed_big_endian() {
+    let scalar = Scalar::<super::Secp256k1>::random();
+    let mut be = scalar.to_bytes().to_vec();
+    be.reverse();
+    assert_eq!(&*scalar.to_bytes_le(), &be[..]);
+}
+
+/// Outputs given 32-byte blocks one by one
+pub(super) struct FixedRng(pub Vec<[u8; 32]>);
+
+impl rand::RngCore for FixedRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.copy_from_slice(&self.0.remove(0))
+    }
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl rand::CryptoRng for FixedRng {}
+
+#[test]
+fn random_scalar_with_rng_rejects_zero_and_out_of_range_values() {
+    use super::Secp256k1;
+
+    let q = Scalar::<Secp256k1>::group_order()
+        .to_bytes_array::<32>()
+        .unwrap();
+    let mut five = [0u8; 32];
+    five[31] = 5;
+    let mut rng = FixedRng(vec![[0u8; 32], q, [0xff; 32], five]);
+
+    let s = Scalar::<Secp256k1>::random_with_rng(&mut rng);
+    assert_eq!(s, Scalar::from(5));
+    assert!(rng.0.is_empty());
+}
+
 test_for_all_curves!(generator_mul_curve_order_is_zero);
 fn generator_mul_curve_order_is_zero<E: Curve>() {
     let g: &E::Point = ECPoint::generator();
@@ -112,6 +294,14 @@ fn generator_mul_curve_order_is_zero<E: Curve>() {
     assert!(g.scalar_mul(&s).add_point(g).is_zero());
 }
 
+test_for_all_curves!(generator_mul_matches_scalar_mul);
+fn generator_mul_matches_scalar_mul<E: Curve>() {
+    let s: E::Scalar = random_nonzero_scalar();
+    let expected = <E::Point as ECPoint>::generator().scalar_mul(&s);
+    assert_eq!(<E::Point as ECPoint>::generator_mul(&s), expected);
+    assert!(<E::Point as ECPoint>::generator_mul(&E::Scalar::zero()).is_zero());
+}
+
 test_for_all_curves!(scalar_behaves_the_same_as_bigint);
 fn scalar_behaves_the_same_as_bigint<E: Curve>() {
     let mut rng = OsRng;
@@ -203,6 +393,36 @@ fn from_coords_produces_the_same_point<E: Curve>() {
     assert_eq!(p, p2);
 }
 
+test_for_all_curves!(from_coords_rejects_point_not_on_curve);
+fn from_coords_rejects_point_not_on_curve<E: Curve>() {
+    if E::CURVE_NAME == "ristretto" {
+        // This curve is exception.
+        return;
+    }
+    let s: E::Scalar = random_nonzero_scalar();
+    let p: E::Point = <E::Point as ECPoint>::generator().scalar_mul(&s);
+    let coords = p.coords().unwrap();
+    let result: Result<E::Point, _> = ECPoint::from_coords(&coords.x, &(coords.y + 1));
+    assert!(result.is_err());
+}
+
+test_for_all_curves!(deserialize_rejects_malformed_bytes);
+fn deserialize_rejects_malformed_bytes<E: Curve>() {
+    let p: E::Point = <E::Point as ECPoint>::generator().scalar_mul(&random_nonzero_scalar());
+    let bytes = p.serialize_compressed();
+    let too_long = [bytes.as_ref(), &[0u8; 16]].concat();
+    let result: Result<E::Point, _> = ECPoint::deserialize(&too_long);
+    assert!(result.is_err());
+
+    if E::CURVE_NAME == "ed25519" {
+        // Non-canonical encodings are reduced rather than rejected by this curve.
+        return;
+    }
+    let garbage = vec![0xffu8; bytes.len()];
+    let result: Result<E::Point, _> = ECPoint::deserialize(&garbage);
+    assert!(result.is_err());
+}
+
 test_for_all_curves!(test_point_addition);
 fn test_point_addition<E: Curve>() {
     let a: E::Scalar = random_nonzero_scalar();
@@ -306,12 +526,126 @@ fn serialize_deserialize_scalar<E: Curve>() {
     }
 }
 
+test_for_all_curves!(small_scalar_serialization_has_fixed_length);
+fn small_scalar_serialization_has_fixed_length<E: Curve>() {
+    let scalar_len = <E::Scalar as ECScalar>::ScalarLength::USIZE;
+    for n in [1u32, 123456] {
+        let s = Scalar::<E>::from(n);
+        let bytes = s.to_bytes();
+        assert_eq!(bytes.len(), scalar_len);
+        assert_eq!(Scalar::<E>::from_bytes(&bytes).unwrap(), s);
+    }
+}
+
+test_for_all_curves!(scalar_bigint_round_trip_with_high_zero_bytes);
+fn scalar_bigint_round_trip_with_high_zero_bytes<E: Curve>() {
+    let scalar_len = <E::Scalar as ECScalar>::ScalarLength::USIZE;
+    let values = [
+        BigInt::one(),
+        BigInt::from(0xff),
+        BigInt::from(1) << (8 * (scalar_len - 2)),
+    ];
+    for n in values.iter() {
+        let s = Scalar::<E>::from_bigint(n);
+        assert_eq!(&s.to_bigint(), n);
+        assert_eq!(Scalar::<E>::from_bigint(&s.to_bigint()), s);
+        assert_eq!(
+            Scalar::<E>::from_bytes(&s.to_bytes()).unwrap().to_bigint(),
+            *n
+        );
+    }
+}
+
 test_for_all_curves!(scalar_invert);
 fn scalar_invert<E: Curve>() {
-    let n: E::Scalar = random_nonzero_scalar();
+    for _ in 0..10 {
+        let n: E::Scalar = random_nonzero_scalar();
+
+        let n_inv = n.invert().unwrap();
+        assert_eq!(n.mul(&n_inv), ECScalar::from_bigint(&BigInt::one()))
+    }
+}
 
-    let n_inv = n.invert().unwrap();
-    assert_eq!(n.mul(&n_inv), ECScalar::from_bigint(&BigInt::one()))
+test_for_all_curves!(scalar_pow);
+fn scalar_pow<E: Curve>() {
+    let s = Scalar::<E>::random();
+    assert_eq!(s.pow(&BigInt::zero()), Scalar::from(1));
+    assert_eq!(s.pow(&BigInt::one()), s);
+    assert_eq!(s.pow(&BigInt::from(3)), &s * &s * &s);
+    assert_eq!(Scalar::<E>::zero().pow(&BigInt::zero()), Scalar::from(1));
+    assert!(Scalar::<E>::zero().pow(&BigInt::from(5)).is_zero());
+
+    // Fermat's little theorem: s^(q-1) = 1, s^(q+1) = s^2
+    let q = Scalar::<E>::group_order();
+    assert_eq!(s.pow(&(q - 1)), Scalar::from(1));
+    assert_eq!(s.pow(&(q + 1)), &s * &s);
+}
+
+test_for_all_curves!(scalar_checked_div);
+fn scalar_checked_div<E: Curve>() {
+    for _ in 0..10 {
+        let a = Scalar::<E>::random();
+        let b = Scalar::<E>::random();
+        let quotient = a.checked_div(&b).unwrap();
+        assert_eq!(quotient * &b, a);
+    }
+    let a = Scalar::<E>::random();
+    assert_eq!(a.checked_div(&Scalar::zero()), None);
+    assert_eq!(Scalar::<E>::zero().checked_div(&a), Some(Scalar::zero()));
+}
+
+test_for_all_curves!(scalar_batch_invert);
+fn scalar_batch_invert<E: Curve>() {
+    let mut scalars: Vec<_> = iter::repeat_with(Scalar::<E>::random).take(16).collect();
+    scalars[3] = Scalar::zero();
+    scalars[15] = Scalar::zero();
+    let expected: Vec<_> = scalars
+        .iter()
+        .map(|s| s.invert().unwrap_or_else(Scalar::zero))
+        .collect();
+
+    Scalar::batch_invert(&mut scalars);
+    assert_eq!(scalars, expected);
+
+    let mut empty: Vec<Scalar<E>> = vec![];
+    Scalar::batch_invert(&mut empty);
+    let mut zeros = vec![Scalar::<E>::zero(); 2];
+    Scalar::batch_invert(&mut zeros);
+    assert!(zeros.iter().all(|s| s.is_zero()));
+}
+
+test_for_all_curves!(scalar_from_wide_bytes_mod_order);
+fn scalar_from_wide_bytes_mod_order<E: Curve>() {
+    use sha2::{Digest, Sha512};
+
+    let hash = Sha512::digest(b"message");
+    assert_eq!(hash.len(), 64);
+    let scalar = Scalar::<E>::from_bytes_mod_order(&hash);
+    let expected = BigInt::from_bytes(&hash) % Scalar::<E>::group_order();
+    assert_eq!(scalar.to_bigint(), expected);
+    assert!(!scalar.is_zero());
+
+    let q = Scalar::<E>::group_order();
+    assert!(Scalar::<E>::from_bytes_mod_order(&q.to_bytes()).is_zero());
+    assert!(Scalar::<E>::from_bytes_mod_order(&[]).is_zero());
+    assert_eq!(
+        Scalar::<E>::from_bytes_mod_order(&(q + 5).to_bytes()),
+        Scalar::from(5)
+    );
+}
+
+test_for_all_curves!(scalar_ct_eq_matches_eq);
+fn scalar_ct_eq_matches_eq<E: Curve>() {
+    use subtle::ConstantTimeEq;
+
+    let a = Scalar::<E>::random();
+    let b = Scalar::<E>::random();
+    for (x, y) in [(&a, &a), (&a, &b), (&b, &a)] {
+        assert_eq!(bool::from(x.ct_eq(y)), x == y);
+    }
+    let zero = Scalar::<E>::zero();
+    assert!(bool::from(zero.ct_eq(&Scalar::zero())));
+    assert!(!bool::from(zero.ct_eq(&a)));
 }
 
 test_for_all_curves!(zero_scalar_invert);
@@ -358,3 +692,47 @@ fn scalar_assign_negation<E: Curve>() {
     };
     assert_eq!(s_neg_1, s_neg_2);
 }
+
+test_for_all_curves!(serialize_points_round_trip);
+fn serialize_points_round_trip<E: Curve>() {
+    let mut points = (0..9)
+        .map(|_| Point::<E>::generator() * Scalar::random())
+        .collect::<Vec<_>>();
+    points.push(Point::zero());
+
+    let bytes = Point::serialize_points(&points);
+    let point_size = <E::Point as ECPoint>::CompressedPointLength::USIZE;
+    assert_eq!(bytes.len(), 2 + 10 * point_size);
+    assert_eq!(Point::<E>::deserialize_points(&bytes).unwrap(), points);
+
+    let empty = Point::<E>::serialize_points(&[]);
+    assert!(Point::<E>::deserialize_points(&empty).unwrap().is_empty());
+    assert!(Point::<E>::deserialize_points(&bytes[..bytes.len() - 1]).is_err());
+    assert!(Point::<E>::deserialize_points(&bytes[..1]).is_err());
+}
+
+#[test]
+fn serialize_points_secp256k1_size() {
+    let points = (0..10)
+        .map(|_| Point::<super::Secp256k1>::generator() * Scalar::random())
+        .collect::<Vec<_>>();
+    assert_eq!(Point::serialize_points(&points).len(), 2 + 10 * 33);
+}
+
+test_for_all_curves!(scalar_from_seed_is_deterministic);
+fn scalar_from_seed_is_deterministic<E: Curve>() {
+    let s1 = Scalar::<E>::from_seed(b"seed", b"info");
+    assert_eq!(s1, Scalar::from_seed(b"seed", b"info"));
+    assert_ne!(s1, Scalar::from_seed(b"seed", b"other info"));
+    assert_ne!(s1, Scalar::from_seed(b"other seed", b"info"));
+}
+
+#[test]
+fn scalar_from_seed_test_vector() {
+    // HKDF-SHA256(salt = 32 zero bytes, ikm = seed, info, L = 64) reduced modulo group order
+    let scalar = Scalar::<super::Secp256k1>::from_seed(b"curv test seed", b"curv test info");
+    assert_eq!(
+        scalar.to_hex(),
+        "ba40eba93f6c5ce955367368a743edf2cb1da761b3df4f7c98290e9d41202030"
+    );
+}
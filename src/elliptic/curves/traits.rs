@@ -9,9 +9,11 @@ use std::fmt;
 
 use generic_array::{ArrayLength, GenericArray};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
 use typenum::Unsigned;
 use zeroize::Zeroize;
 
+use crate::arithmetic::Converter;
 use crate::BigInt;
 
 /// Elliptic curve implementation
@@ -60,6 +62,8 @@ pub trait ECScalar: Clone + PartialEq + fmt::Debug + 'static {
     /// Serializes scalar into bytes
     fn serialize(&self) -> GenericArray<u8, Self::ScalarLength>;
     /// Deserializes scalar from bytes
+    ///
+    /// Must reject non-canonical encodings, i.e. integers greater than or equal to group order.
     fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError>;
 
     /// Calculates `(self + other) mod group_order`
@@ -195,6 +199,9 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + 'static {
     }
 
     /// Multiplies the point at scalar value
+    ///
+    /// Whether multiplication is constant time depends on underlying library, see implementation
+    /// notes of particular curve.
     fn scalar_mul(&self, scalar: &Self::Scalar) -> Self;
     /// Multiplies curve generator at given scalar
     ///
@@ -203,6 +210,18 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + 'static {
     fn generator_mul(scalar: &Self::Scalar) -> Self {
         Self::generator().scalar_mul(scalar)
     }
+    /// Hashes a list of points into a scalar
+    ///
+    /// Compressed encodings of the points are hashed with SHA-512, and 512 bits digest is reduced
+    /// modulo [group order](ECScalar::group_order). Compressed encodings have fixed length, so the
+    /// list is encoded unambiguously.
+    fn hash_points_to_scalar(points: &[&Self]) -> Self::Scalar {
+        let mut hasher = Sha512::new();
+        for point in points {
+            hasher.update(point.serialize_compressed());
+        }
+        Self::Scalar::from_bigint(&BigInt::from_bytes(&hasher.finalize()))
+    }
     /// Adds two points
     fn add_point(&self, other: &Self) -> Self;
     /// Substrates `other` from `self`
@@ -488,4 +488,90 @@ mod test {
             }
         }
     }
+
+    crate::test_for_all_curves!(negation_produces_additive_inverse);
+    fn negation_produces_additive_inverse<E: Curve>() {
+        let s = Scalar::<E>::random();
+        assert!((&s + -&s).is_zero());
+        assert!((-s.clone() + s).is_zero());
+
+        let p = Point::<E>::generator() * Scalar::random();
+        assert!((&p + -&p).is_zero());
+        assert!((-p.clone() + p).is_zero());
+
+        let g = Point::<E>::generator();
+        assert!((-g + g).is_zero());
+    }
+
+    crate::test_for_all_curves!(point_subtraction_reverts_addition);
+    fn point_subtraction_reverts_addition<E: Curve>() {
+        let p = Point::<E>::generator() * Scalar::random();
+        let q = Point::<E>::generator() * Scalar::random();
+        assert_eq!(&p + &q - &q, p);
+        assert_eq!(&p - &q + &q, p);
+        assert_eq!(&p + Point::generator() - Point::generator(), p);
+    }
+
+    crate::test_for_all_curves!(sum_of_points);
+    fn sum_of_points<E: Curve>() {
+        let empty: Vec<Point<E>> = vec![];
+        assert!(empty.iter().sum::<Point<E>>().is_zero());
+        assert!(empty.into_iter().sum::<Point<E>>().is_zero());
+
+        let p = Point::<E>::generator() * Scalar::random();
+        assert_eq!(std::iter::once(&p).sum::<Point<E>>(), p);
+
+        let scalars = (0..5).map(|_| Scalar::<E>::random()).collect::<Vec<_>>();
+        let points = scalars
+            .iter()
+            .map(|s| Point::generator() * s)
+            .collect::<Vec<_>>();
+        let expected = Point::generator() * scalars.iter().sum::<Scalar<E>>();
+        assert_eq!(points.iter().sum::<Point<E>>(), expected);
+        assert_eq!(points.into_iter().sum::<Point<E>>(), expected);
+    }
+
+    crate::test_for_all_curves!(multiscalar_mul_matches_naive_computation);
+    fn multiscalar_mul_matches_naive_computation<E: Curve>() {
+        let scalars = (0..8).map(|_| Scalar::<E>::random()).collect::<Vec<_>>();
+        let points = (0..8)
+            .map(|_| Point::<E>::generator() * Scalar::random())
+            .collect::<Vec<_>>();
+        let expected: Point<E> = scalars.iter().zip(&points).map(|(s, p)| s * p).sum();
+        assert_eq!(Point::multiscalar_mul(&scalars, &points), expected);
+
+        let expected: Point<E> = scalars[..2].iter().zip(&points).map(|(s, p)| s * p).sum();
+        for window_bits in [1, 8] {
+            assert_eq!(
+                Point::multiscalar_mul_with_window(&scalars[..2], &points[..2], window_bits),
+                expected
+            );
+        }
+    }
+
+    crate::test_for_all_curves!(multiscalar_mul_edge_cases);
+    fn multiscalar_mul_edge_cases<E: Curve>() {
+        assert!(Point::<E>::multiscalar_mul(&[], &[]).is_zero());
+
+        let p = Point::<E>::generator() * Scalar::random();
+        let scalars = [Scalar::zero(), Scalar::from(1), -Scalar::from(1)];
+        let points = [p.clone(), p.clone(), Point::zero()];
+        assert_eq!(Point::multiscalar_mul(&scalars, &points), p);
+    }
+
+    crate::test_for_all_curves!(operators_match_trait_methods);
+    fn operators_match_trait_methods<E: Curve>() {
+        let a = Scalar::<E>::random();
+        let b = Scalar::<E>::random();
+        assert_eq!((&a + &b).as_raw(), &a.as_raw().add(b.as_raw()));
+        assert_eq!((&a - &b).as_raw(), &a.as_raw().sub(b.as_raw()));
+        assert_eq!((&a * &b).as_raw(), &a.as_raw().mul(b.as_raw()));
+
+        let p = Point::<E>::generator() * &a;
+        let q = Point::<E>::generator() * &b;
+        assert_eq!((&p + &q).as_raw(), &p.as_raw().add_point(q.as_raw()));
+        assert_eq!((&p - &q).as_raw(), &p.as_raw().sub_point(q.as_raw()));
+        assert_eq!((&a * &p).as_raw(), &p.as_raw().scalar_mul(a.as_raw()));
+        assert_eq!((&p * &a).as_raw(), &p.as_raw().scalar_mul(a.as_raw()));
+    }
 }
@@ -9,6 +9,14 @@ pub struct EncodedScalar<E: Curve> {
     bytes: GenericArray<u8, <E::Scalar as ECScalar>::ScalarLength>,
 }
 
+impl<E: Curve> EncodedScalar<E> {
+    pub(crate) fn from_array(
+        bytes: GenericArray<u8, <E::Scalar as ECScalar>::ScalarLength>,
+    ) -> Self {
+        Self { bytes }
+    }
+}
+
 impl<E: Curve> Deref for EncodedScalar<E> {
     type Target = [u8];
     fn deref(&self) -> &Self::Target {
@@ -22,6 +22,22 @@ pub enum PointFromBytesError {
     InvalidPoint(MismatchedPointOrder),
 }
 
+#[derive(Debug, Error)]
+pub enum PointFromHexError {
+    #[error("invalid hex: {0}")]
+    InvalidHex(hex::FromHexError),
+    #[error(transparent)]
+    InvalidPoint(PointFromBytesError),
+}
+
+#[derive(Debug, Error)]
+pub enum ScalarFromHexError {
+    #[error("invalid hex: {0}")]
+    InvalidHex(hex::FromHexError),
+    #[error("invalid scalar: {0}")]
+    InvalidScalar(DeserializationError),
+}
+
 #[derive(Debug, Error)]
 pub enum PointFromCoordsError {
     #[error("{}", NotOnCurve)]
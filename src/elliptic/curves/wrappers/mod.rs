@@ -9,5 +9,5 @@ mod serde_support;
 
 pub use self::{
     encoded_point::EncodedPoint, encoded_scalar::EncodedScalar, generator::Generator, point::Point,
-    scalar::Scalar,
+    scalar::Scalar, serde_support::BINARY_FORMAT_VERSION,
 };
@@ -1,11 +1,17 @@
-use std::{fmt, iter};
+use std::{convert::TryFrom, fmt, iter, str::FromStr};
 
+use typenum::Unsigned;
+
+use crate::arithmetic::*;
 use crate::elliptic::curves::traits::*;
 use crate::BigInt;
 
 use super::{
-    error::{MismatchedPointOrder, PointFromBytesError, PointFromCoordsError, ZeroPointError},
-    EncodedPoint, Generator,
+    error::{
+        MismatchedPointOrder, PointFromBytesError, PointFromCoordsError, PointFromHexError,
+        ZeroPointError,
+    },
+    EncodedPoint, Generator, Scalar,
 };
 use crate::elliptic::curves::wrappers::encoded_point::EncodedPointChoice;
 
@@ -69,6 +75,19 @@ impl<E: Curve> Point<E> {
     ///
     /// Returns a structure holding a static reference on actual value (in most cases referenced
     /// value is fine). Use [`.to_point()`](Generator::to_point) if you need to take it by value.
+    ///
+    /// Multiplying the generator by a scalar (e.g. to derive a public key from a secret key) uses
+    /// curve-specific [generator multiplication](ECPoint::generator_mul), which is usually faster
+    /// than multiplying a regular point:
+    ///
+    /// ```rust
+    /// # use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+    /// let secret_key = Scalar::<Secp256k1>::random();
+    /// let public_key = Point::generator() * &secret_key;
+    ///
+    /// let one = Scalar::<Secp256k1>::from(1);
+    /// assert_eq!(Point::generator() * one, Point::generator().to_point());
+    /// ```
     pub fn generator() -> Generator<E> {
         Generator::default()
     }
@@ -79,6 +98,26 @@ impl<E: Curve> Point<E> {
     ///
     /// Returns a static reference to actual point — in most cases referenced value is fine. Use
     /// `.clone()` to take it by value.
+    ///
+    /// For secp256k1, x coordinate of the point is obtained by applying SHA256 three times to
+    /// compressed generator, so anyone can verify the derivation:
+    ///
+    /// ```rust
+    /// # use curv::elliptic::curves::{Point, Secp256k1};
+    /// # use curv::BigInt;
+    /// # use curv::arithmetic::Converter;
+    /// use sha2::{Digest, Sha256};
+    ///
+    /// let g = Point::<Secp256k1>::generator().to_bytes(true);
+    /// let x = Sha256::digest(&Sha256::digest(&Sha256::digest(&g)));
+    ///
+    /// let h = Point::<Secp256k1>::base_point2();
+    /// assert_eq!(h.x_coord(), Some(BigInt::from_bytes(&x)));
+    /// assert_eq!(
+    ///     hex::encode(&*h.to_bytes(true)),
+    ///     "0208d13221e3a7326a34dd45214ba80116dd142e4b5ff3ce66a8dc7bfa0378b795",
+    /// );
+    /// ```
     pub fn base_point2() -> &'static Self {
         let p = E::Point::base_point2();
         // Safety: we proof that base_point2 has correct order
@@ -106,6 +145,90 @@ impl<E: Curve> Point<E> {
         self.as_raw().is_zero()
     }
 
+    /// Computes multi-scalar multiplication `s_1 * P_1 + ... + s_n * P_n`
+    ///
+    /// Uses Straus' method with window of 4 bits, which is considerably faster than computing
+    /// every product separately. See [multiscalar_mul_with_window](Self::multiscalar_mul_with_window)
+    /// to choose the window size.
+    ///
+    /// Note that the computation is not constant time, so it's suitable for verification
+    /// (e.g. of proofs or commitments) rather than for operations involving secret scalars.
+    ///
+    /// ## Panics
+    /// Panics if `scalars` and `points` have different lengths.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use curv::elliptic::curves::{Point, Scalar, Secp256k1};
+    /// let scalars = [Scalar::<Secp256k1>::random(), Scalar::random()];
+    /// let points = [Point::generator().to_point(), Point::base_point2().clone()];
+    ///
+    /// let sum = Point::multiscalar_mul(&scalars, &points);
+    /// assert_eq!(sum, &scalars[0] * &points[0] + &scalars[1] * &points[1]);
+    /// ```
+    pub fn multiscalar_mul(scalars: &[Scalar<E>], points: &[Point<E>]) -> Self {
+        Self::multiscalar_mul_with_window(scalars, points, 4)
+    }
+
+    /// Computes multi-scalar multiplication `s_1 * P_1 + ... + s_n * P_n` using a window of
+    /// `window_bits` bits
+    ///
+    /// Larger window requires fewer additions but precomputes `2^window_bits` multiples of every
+    /// point, so optimal value depends on the number of points.
+    ///
+    /// ## Panics
+    /// Panics if `scalars` and `points` have different lengths, or if `window_bits` is not in range
+    /// `1..=8`.
+    pub fn multiscalar_mul_with_window(
+        scalars: &[Scalar<E>],
+        points: &[Point<E>],
+        window_bits: usize,
+    ) -> Self {
+        assert_eq!(
+            scalars.len(),
+            points.len(),
+            "number of scalars doesn't match number of points"
+        );
+        assert!(
+            (1..=8).contains(&window_bits),
+            "window size must be in range 1..=8"
+        );
+
+        // tables[i][d] = d * P_i
+        let tables = points
+            .iter()
+            .map(|point| {
+                let mut table = Vec::with_capacity(1 << window_bits);
+                table.push(Point::zero());
+                for d in 1..(1 << window_bits) {
+                    let next = &table[d - 1] + point;
+                    table.push(next);
+                }
+                table
+            })
+            .collect::<Vec<_>>();
+        let scalars = scalars.iter().map(|s| s.to_bigint()).collect::<Vec<_>>();
+
+        let bits = Scalar::<E>::group_order().bit_length();
+        let windows = (bits + window_bits - 1) / window_bits;
+
+        let mut result = Point::zero();
+        for w in (0..windows).rev() {
+            for _ in 0..window_bits {
+                result = &result + &result;
+            }
+            for (scalar, table) in scalars.iter().zip(&tables) {
+                let digit = (0..window_bits)
+                    .filter(|b| scalar.test_bit(w * window_bits + b))
+                    .fold(0usize, |acc, b| acc | (1 << b));
+                if digit != 0 {
+                    result = result + &table[digit];
+                }
+            }
+        }
+        result
+    }
+
     /// Returns point coordinates
     ///
     /// Point might not have coordinates (specifically, "point at infinity" doesn't), in this case
@@ -149,6 +272,17 @@ impl<E: Curve> Point<E> {
     }
 
     /// Serializes a point in (un)compressed form
+    ///
+    /// Returned [EncodedPoint] derefs to `[u8]`. Use `.to_vec()` if you need an owned `Vec<u8>`
+    /// (e.g. to pass it to JS via wasm-bindgen):
+    ///
+    /// ```rust
+    /// # use curv::elliptic::curves::{Point, Secp256k1};
+    /// let point = Point::<Secp256k1>::generator().to_point();
+    /// let bytes: Vec<u8> = point.to_bytes(true).to_vec();
+    /// assert_eq!(bytes.len(), 33);
+    /// assert_eq!(Point::<Secp256k1>::from_bytes(&bytes).unwrap(), point);
+    /// ```
     pub fn to_bytes(&self, compressed: bool) -> EncodedPoint<E> {
         if compressed {
             EncodedPoint(EncodedPointChoice::Compressed(
@@ -161,6 +295,61 @@ impl<E: Curve> Point<E> {
         }
     }
 
+    /// Parses a point from hex string
+    ///
+    /// Accepts both compressed and uncompressed encodings, see [from_bytes](Self::from_bytes)
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use curv::elliptic::curves::{Point, Secp256k1};
+    /// let point = Point::<Secp256k1>::from_hex(
+    ///     "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    /// ).unwrap();
+    /// assert_eq!(point, Point::generator().to_point());
+    /// assert!(Point::<Secp256k1>::from_hex("not a hex").is_err());
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, PointFromHexError> {
+        let bytes = hex::decode(hex).map_err(PointFromHexError::InvalidHex)?;
+        Self::from_bytes(&bytes).map_err(PointFromHexError::InvalidPoint)
+    }
+
+    /// Serializes a point in compressed form as hex string
+    pub fn to_hex(&self) -> String {
+        hex::encode(&*self.to_bytes(true))
+    }
+
+    /// Serializes a list of points in compact binary form
+    ///
+    /// Output consists of 2 bytes big-endian length prefix followed by concatenation of points in
+    /// compressed form. E.g. 10 secp256k1 points take `2 + 10*33` bytes.
+    ///
+    /// ## Panics
+    /// Panics if there are more than `u16::MAX` points
+    pub fn serialize_points(points: &[Self]) -> Vec<u8> {
+        let len = u16::try_from(points.len()).expect("too many points");
+        let point_size = <E::Point as ECPoint>::CompressedPointLength::USIZE;
+        let mut bytes = Vec::with_capacity(2 + points.len() * point_size);
+        bytes.extend_from_slice(&len.to_be_bytes());
+        for point in points {
+            bytes.extend_from_slice(&point.to_bytes(true));
+        }
+        bytes
+    }
+
+    /// Parses a list of points serialized by [serialize_points](Self::serialize_points)
+    pub fn deserialize_points(bytes: &[u8]) -> Result<Vec<Self>, PointFromBytesError> {
+        let point_size = <E::Point as ECPoint>::CompressedPointLength::USIZE;
+        if bytes.len() < 2 {
+            return Err(PointFromBytesError::DeserializationError);
+        }
+        let (len, points) = bytes.split_at(2);
+        let len = usize::from(u16::from_be_bytes([len[0], len[1]]));
+        if points.len() != len * point_size {
+            return Err(PointFromBytesError::DeserializationError);
+        }
+        points.chunks(point_size).map(Self::from_bytes).collect()
+    }
+
     /// Constructs a `Point<E>` from low-level [ECPoint] implementor
     ///
     /// Returns error if point is not valid. Valid point is either a zero point, or a point of
@@ -283,6 +472,20 @@ impl<E: Curve> Clone for Point<E> {
     }
 }
 
+/// Displays point in compressed form as hex string, see [to_hex](Point::to_hex)
+impl<E: Curve> fmt::Display for Point<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl<E: Curve> FromStr for Point<E> {
+    type Err = PointFromHexError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
 impl<E: Curve> fmt::Debug for Point<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.raw_point.fmt(f)
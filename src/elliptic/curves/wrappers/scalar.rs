@@ -1,8 +1,16 @@
-use std::{fmt, iter};
+use std::{fmt, iter, str::FromStr};
 
+use generic_array::{typenum::Unsigned, GenericArray};
+use hmac::{Hmac, Mac, NewMac};
+use rand::{CryptoRng, RngCore};
+use sha2::Sha256;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroizing;
+
+use crate::arithmetic::*;
 use crate::elliptic::curves::traits::{Curve, ECScalar};
 use crate::elliptic::curves::wrappers::encoded_scalar::EncodedScalar;
-use crate::elliptic::curves::{DeserializationError, ZeroScalarError};
+use crate::elliptic::curves::{DeserializationError, ScalarFromHexError, ZeroScalarError};
 use crate::BigInt;
 
 /// Scalar value in a prime field
@@ -47,7 +55,22 @@ impl<E: Curve> Scalar<E> {
         }
     }
 
+    /// Checks that scalar is in range `[1, q)`, where `q` is [group order](Self::group_order)
+    ///
+    /// Scalar is always kept reduced modulo `q`, so this only checks that it's nonzero. See
+    /// [ensure_nonzero](Self::ensure_nonzero) if you prefer a `Result`.
+    pub fn is_valid(&self) -> bool {
+        !self.is_zero()
+    }
+
     /// Samples a random nonzero scalar
+    ///
+    /// Output is uniformly distributed in `[1, q)`, where `q` is [group order](Self::group_order):
+    /// zero scalar is never returned, it's resampled instead.
+    ///
+    /// Randomness is taken from system entropy ([OsRng](rand::rngs::OsRng)) on every curve, no
+    /// thread-local RNG is involved. Use [random_with_rng](Self::random_with_rng) to provide
+    /// another source of randomness.
     pub fn random() -> Self {
         loop {
             let s = E::Scalar::random();
@@ -57,6 +80,52 @@ impl<E: Curve> Scalar<E> {
         }
     }
 
+    /// Samples a random nonzero scalar using given source of randomness
+    ///
+    /// Output is uniformly distributed in `[1, q)`. Unlike [random](Self::random), output is fully determined by `rng`, which is useful for
+    /// reproducible tests or for deriving keys from a seeded CSPRNG.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use curv::elliptic::curves::{Scalar, Secp256k1};
+    /// use rand::{rngs::StdRng, SeedableRng};
+    ///
+    /// let s1 = Scalar::<Secp256k1>::random_with_rng(&mut StdRng::seed_from_u64(42));
+    /// let s2 = Scalar::<Secp256k1>::random_with_rng(&mut StdRng::seed_from_u64(42));
+    /// assert_eq!(s1, s2);
+    /// ```
+    pub fn random_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let group_order = Self::group_order();
+        let bits = group_order.bit_length();
+        let mut bytes = Zeroizing::new(vec![0u8; (bits + 7) / 8]);
+        loop {
+            rng.fill_bytes(&mut bytes);
+            // Rejection sampling: truncate to bit length of group order, retry if out of range
+            bytes[0] &= 0xff >> (bytes.len() * 8 - bits);
+            let n = Zeroizing::new(BigInt::from_bytes(&bytes));
+            if !n.is_zero() && &*n < group_order {
+                break Scalar::from_bigint(&n);
+            }
+        }
+    }
+
+    /// Samples `n` random nonzero scalars
+    ///
+    /// Scalars are drawn from system entropy ([OsRng](rand::rngs::OsRng)), use
+    /// [random_vec_with_rng](Self::random_vec_with_rng) to provide another source of randomness.
+    pub fn random_vec(n: usize) -> Vec<Self> {
+        Self::random_vec_with_rng(n, &mut rand::rngs::OsRng)
+    }
+
+    /// Samples `n` random nonzero scalars using given source of randomness
+    ///
+    /// See [random_with_rng](Self::random_with_rng)
+    pub fn random_vec_with_rng<R: RngCore + CryptoRng>(n: usize, rng: &mut R) -> Vec<Self> {
+        iter::repeat_with(|| Self::random_with_rng(rng))
+            .take(n)
+            .collect()
+    }
+
     /// Constructs zero scalar
     pub fn zero() -> Self {
         Self::from_raw(E::Scalar::zero())
@@ -68,6 +137,10 @@ impl<E: Curve> Scalar<E> {
     }
 
     /// Converts a scalar to [BigInt]
+    ///
+    /// Note that resulting BigInt doesn't keep leading zeroes, so its byte length might be smaller
+    /// than [scalar length](Self::to_bytes). Use [to_bytes](Self::to_bytes) if you need fixed-width
+    /// encoding. Conversion back via [from_bigint](Self::from_bigint) always gives the same scalar.
     pub fn to_bigint(&self) -> BigInt {
         self.as_raw().to_bigint()
     }
@@ -78,15 +151,149 @@ impl<E: Curve> Scalar<E> {
     }
 
     /// Serializes a scalar to bytes
+    ///
+    /// Scalar is encoded with fixed width in curve-specific byte order: big-endian for all curves
+    /// except Ed25519 and Ristretto, which follow `curve25519-dalek` and use little-endian. Use
+    /// [to_bytes_le](Self::to_bytes_le) if you need little-endian encoding regardless of the curve.
+    ///
+    /// Returned [EncodedScalar] derefs to `[u8]`, use `.to_vec()` if you need an owned `Vec<u8>`:
+    ///
+    /// ```rust
+    /// # use curv::elliptic::curves::{Scalar, Secp256k1};
+    /// let scalar = Scalar::<Secp256k1>::random();
+    /// let bytes: Vec<u8> = scalar.to_bytes().to_vec();
+    /// assert_eq!(bytes.len(), 32);
+    /// assert_eq!(Scalar::<Secp256k1>::from_bytes(&bytes).unwrap(), scalar);
+    /// ```
     pub fn to_bytes(&self) -> EncodedScalar<E> {
         EncodedScalar::from(self)
     }
 
     /// Constructs a scalar from bytes
+    ///
+    /// Expects fixed width encoding produced by [to_bytes](Self::to_bytes). Use
+    /// [from_bytes_le](Self::from_bytes_le) for little-endian input.
+    ///
+    /// Validates the input: returns error if encoded integer is not less than
+    /// [group order](Self::group_order). Deserializing scalars (via serde) performs the same check.
+    /// In contrast, [from_bigint](Self::from_bigint) and
+    /// [from_bytes_mod_order](Self::from_bytes_mod_order) reduce their input modulo group order
+    /// and never fail.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
         ECScalar::deserialize(bytes).map(Self::from_raw)
     }
 
+    /// Serializes a scalar to fixed width little-endian bytes
+    ///
+    /// Unlike [to_bytes](Self::to_bytes), byte order doesn't depend on the curve: least
+    /// significant byte always goes first.
+    pub fn to_bytes_le(&self) -> EncodedScalar<E> {
+        let be = Zeroizing::new(self.to_bigint().to_bytes());
+        let mut bytes = GenericArray::default();
+        for (dst, src) in bytes.iter_mut().zip(be.iter().rev()) {
+            *dst = *src;
+        }
+        EncodedScalar::from_array(bytes)
+    }
+
+    /// Constructs a scalar from fixed width little-endian bytes produced by
+    /// [to_bytes_le](Self::to_bytes_le)
+    ///
+    /// Returns error if input length doesn't match scalar length, or if encoded integer is not
+    /// less than group order.
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        if bytes.len() != <E::Scalar as ECScalar>::ScalarLength::USIZE {
+            return Err(DeserializationError);
+        }
+        let mut be = Zeroizing::new(bytes.to_vec());
+        be.reverse();
+        let n = BigInt::from_bytes(&be);
+        if &n >= Self::group_order() {
+            return Err(DeserializationError);
+        }
+        Ok(Self::from_bigint(&n))
+    }
+
+    /// Parses a scalar from hex string
+    ///
+    /// Expects the same (big-endian, fixed length) encoding as [from_bytes](Self::from_bytes).
+    pub fn from_hex(hex: &str) -> Result<Self, ScalarFromHexError> {
+        let bytes = Zeroizing::new(hex::decode(hex).map_err(ScalarFromHexError::InvalidHex)?);
+        Self::from_bytes(&bytes).map_err(ScalarFromHexError::InvalidScalar)
+    }
+
+    /// Serializes a scalar as hex string
+    pub fn to_hex(&self) -> String {
+        hex::encode(&*self.to_bytes())
+    }
+
+    /// Interprets `bytes` as big-endian integer and reduces it modulo group order
+    ///
+    /// Unlike [from_bytes](Self::from_bytes), accepts input of any length. It's intended for
+    /// deriving scalars from uniformly random bytes (e.g. hash output). Reducing `n`-bit uniform
+    /// input gives a scalar whose distribution is at most `q / 2^n` away from uniform, so input
+    /// should be at least 128 bits longer than group order `q` (e.g. 48 or 64 bytes for 256-bit
+    /// curves). Whether 32 bytes input is good enough depends on how close `q` is to `2^256`:
+    /// for secp256k1 and Ed25519 statistical distance from uniform is about `2^-127` (for
+    /// Ed25519, every scalar is hit by either 15 or 16 inputs), whereas for P-256 it's about
+    /// `2^-32`, and for BLS12-381 (`q ≈ 2^254.9`) some scalars are 3/2 times more likely than
+    /// others.
+    ///
+    /// Note that reduction goes through [BigInt] and isn't constant time.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use curv::elliptic::curves::{Scalar, Secp256k1};
+    /// use sha2::{Digest, Sha512};
+    ///
+    /// let wide_hash = Sha512::digest(b"some message");
+    /// let scalar = Scalar::<Secp256k1>::from_bytes_mod_order(&wide_hash);
+    /// ```
+    pub fn from_bytes_mod_order(bytes: &[u8]) -> Self {
+        let n = Zeroizing::new(BigInt::from_bytes(bytes));
+        Self::from_bigint(&n)
+    }
+
+    /// Deterministically derives a scalar from a high-entropy `seed`
+    ///
+    /// Uses HKDF-SHA256 ([RFC5869](https://tools.ietf.org/html/rfc5869)) with empty salt (i.e.
+    /// 32 zero bytes) and given `info` to derive 64 bytes which are then reduced via
+    /// [from_bytes_mod_order](Self::from_bytes_mod_order). Different `info` values result into
+    /// independent scalars derived from the same seed.
+    ///
+    /// HKDF doesn't slow down brute-forcing, so low-entropy passwords must be stretched with a
+    /// password hashing function (e.g. Argon2 or scrypt) before being used as a seed.
+    ///
+    /// ## Example
+    /// ```rust
+    /// # use curv::elliptic::curves::{Scalar, Secp256k1};
+    /// let seed = [7u8; 32];
+    /// let signing_key = Scalar::<Secp256k1>::from_seed(&seed, b"signing");
+    /// let encryption_key = Scalar::<Secp256k1>::from_seed(&seed, b"encryption");
+    /// assert_ne!(signing_key, encryption_key);
+    /// assert_eq!(signing_key, Scalar::from_seed(&seed, b"signing"));
+    /// ```
+    pub fn from_seed(seed: &[u8], info: &[u8]) -> Self {
+        type HmacSha256 = Hmac<Sha256>;
+        let hmac = |key: &[u8], data: &[&[u8]]| {
+            let mut mac =
+                HmacSha256::new_from_slice(key).expect("HMAC must take a key of any length");
+            for d in data {
+                mac.update(d);
+            }
+            Zeroizing::new(mac.finalize().into_bytes().to_vec())
+        };
+        // HKDF-Extract
+        let prk = hmac(&[0u8; 32], &[seed]);
+        // HKDF-Expand with L = 64
+        let t1 = hmac(&prk, &[info, &[1]]);
+        let t2 = hmac(&prk, &[&t1, info, &[2]]);
+        let mut okm = Zeroizing::new([0u8; 64]);
+        okm[..32].copy_from_slice(&t1);
+        okm[32..].copy_from_slice(&t2);
+        Self::from_bytes_mod_order(&okm[..])
+    }
+
     /// Returns an order of generator point
     pub fn group_order() -> &'static BigInt {
         E::Scalar::group_order()
@@ -97,6 +304,66 @@ impl<E: Curve> Scalar<E> {
         self.as_raw().invert().map(Self::from_raw)
     }
 
+    /// Multiplies scalar at a small integer `n`
+    ///
+    /// Computes `n·self mod q` via double-and-add over scalar additions, so `n` doesn't need to be
+    /// converted into a scalar. Takes `2·log2(n)` additions at most.
+    pub fn mul_small(&self, n: u64) -> Self {
+        let mut result = E::Scalar::zero();
+        for i in (0..64 - n.leading_zeros()).rev() {
+            result.add_assign(&result.clone());
+            if n >> i & 1 == 1 {
+                result.add_assign(self.as_raw());
+            }
+        }
+        Self::from_raw(result)
+    }
+
+    /// Inverts every scalar in `scalars` in place
+    ///
+    /// Uses Montgomery's trick: `n` inversions are replaced with a single inversion and `3n`
+    /// multiplications. Zero scalars can't be inverted, they're left unchanged.
+    pub fn batch_invert(scalars: &mut [Scalar<E>]) {
+        let mut products = Vec::with_capacity(scalars.len());
+        let mut acc = Scalar::<E>::from(1);
+        for s in scalars.iter().filter(|s| !s.is_zero()) {
+            products.push(acc.clone());
+            acc = acc * s;
+        }
+
+        let mut acc_inv = acc.invert().expect("product of nonzero scalars is nonzero");
+        let nonzero_scalars = scalars.iter_mut().rev().filter(|s| !s.is_zero());
+        for (s, product) in nonzero_scalars.zip(products.iter().rev()) {
+            let next_acc_inv = &acc_inv * &*s;
+            *s = &acc_inv * product;
+            acc_inv = next_acc_inv;
+        }
+    }
+
+    /// Returns division `self * other^-1 mod group_order`, or None if `other` is zero
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        other.invert().map(|inv| self * inv)
+    }
+
+    /// Returns exponentiation `self^exponent mod group_order`
+    ///
+    /// `s.pow(&BigInt::zero())` equals to 1 for any `s` (including zero scalar). Exponent might be
+    /// larger than group order.
+    ///
+    /// ## Panics
+    /// Panics if exponent is negative
+    pub fn pow(&self, exponent: &BigInt) -> Self {
+        assert!(
+            !BigInt::is_negative(exponent),
+            "exponent must be non-negative"
+        );
+        Self::from_bigint(&BigInt::mod_pow(
+            &self.to_bigint(),
+            exponent,
+            Self::group_order(),
+        ))
+    }
+
     /// Constructs a `Scalar<E>` from low-level [ECScalar] implementor
     ///
     /// Typically, you don't need to use this constructor. See [random](Self::random),
@@ -141,12 +408,47 @@ impl<E: Curve> fmt::Debug for Scalar<E> {
     }
 }
 
+/// Displays scalar as hex string, see [to_hex](Scalar::to_hex)
+///
+/// Scalars are often secret, make sure not to display them in logs.
+impl<E: Curve> fmt::Display for Scalar<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl<E: Curve> FromStr for Scalar<E> {
+    type Err = ScalarFromHexError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
+}
+
+/// Note that comparison via `==` is not guaranteed to be constant time. Use it for non-secret
+/// values only, otherwise see [ConstantTimeEq] implementation.
 impl<E: Curve> PartialEq for Scalar<E> {
     fn eq(&self, other: &Self) -> bool {
         self.as_raw().eq(other.as_raw())
     }
 }
 
+/// Compares scalars in constant time
+///
+/// ## Example
+/// ```rust
+/// # use curv::elliptic::curves::{Scalar, Secp256k1};
+/// use subtle::ConstantTimeEq;
+///
+/// let a = Scalar::<Secp256k1>::random();
+/// let b = a.clone();
+/// assert!(bool::from(a.ct_eq(&b)));
+/// ```
+impl<E: Curve> ConstantTimeEq for Scalar<E> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.to_bytes().ct_eq(&other.to_bytes())
+    }
+}
+
 impl<E: Curve> From<u16> for Scalar<E> {
     fn from(n: u16) -> Self {
         Self::from(&BigInt::from(n))
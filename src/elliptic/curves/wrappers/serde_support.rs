@@ -1,6 +1,7 @@
 use std::fmt;
 use std::marker::PhantomData;
 
+use serde::de::value::StrDeserializer;
 use serde::de::{Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
 use serde::ser::SerializeStruct;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -8,9 +9,48 @@ use serde_bytes::Bytes;
 
 use generic_array::GenericArray;
 use typenum::Unsigned;
+use zeroize::Zeroizing;
 
+use crate::arithmetic::{BigInt, Converter};
 use crate::elliptic::curves::{Curve, ECPoint, ECScalar, Point, Scalar};
 
+/// Version of binary encoding of points and scalars
+///
+/// In binary (non human-readable) formats, bytes of a point or a scalar are prefixed with this tag,
+/// so blobs written in an incompatible format are detected on deserialization instead of being
+/// misparsed. Known versions:
+///
+/// * `1` — format used up to curv v0.9. Bytes are not prefixed with a tag, such blobs are rejected.
+/// * `2` — current format. Bytes are prefixed with tag `2`.
+///
+/// Human-readable formats (e.g. json) encode points and scalars as hex strings without a tag.
+pub const BINARY_FORMAT_VERSION: u8 = 2;
+
+/// Checks that `bytes` start with [BINARY_FORMAT_VERSION] tag, returns remaining bytes
+///
+/// `untagged_lens` are lengths of valid untagged encodings which are distinguished to provide
+/// a clear error for blobs serialized in format v1.
+fn strip_version_tag<'b, Err: Error>(
+    bytes: &'b [u8],
+    untagged_lens: &[usize],
+) -> Result<&'b [u8], Err> {
+    if untagged_lens.contains(&bytes.len()) {
+        return Err(Err::custom(format!(
+            "binary format version tag is missing: data was probably serialized by curv v0.9 or \
+             older (format v1), expected format v{}",
+            BINARY_FORMAT_VERSION
+        )));
+    }
+    match bytes.split_first() {
+        Some((&BINARY_FORMAT_VERSION, rest)) => Ok(rest),
+        Some((version, _)) => Err(Err::custom(format!(
+            "unsupported binary format version {}, expected {}",
+            version, BINARY_FORMAT_VERSION
+        ))),
+        None => Err(Err::custom("binary format version tag is missing")),
+    }
+}
+
 // ---
 // --- Point (de)serialization
 // ---
@@ -24,10 +64,14 @@ impl<'p, E: Curve> Serialize for Point<E> {
         let mut s = serializer.serialize_struct("Point", 2)?;
         s.serialize_field("curve", E::CURVE_NAME)?;
         if !is_human_readable {
+            let bytes = self.to_bytes(true);
+            let mut tagged = Vec::with_capacity(1 + bytes.len());
+            tagged.push(BINARY_FORMAT_VERSION);
+            tagged.extend_from_slice(&bytes);
             s.serialize_field(
                 "point",
                 // Serializes bytes efficiently
-                Bytes::new(&self.to_bytes(true)),
+                Bytes::new(&tagged),
             )?;
         } else {
             s.serialize_field("point", &hex::encode(&*self.to_bytes(true)))?;
@@ -56,6 +100,8 @@ impl<'de, E: Curve> Deserialize<'de> for Point<E> {
             {
                 let mut curve_name: Option<CurveNameGuard<E>> = None;
                 let mut point: Option<PointFromBytes<E>> = None;
+                let mut x: Option<String> = None;
+                let mut y: Option<String> = None;
 
                 while let Some(key) = map.next_key()? {
                     match key {
@@ -71,12 +117,47 @@ impl<'de, E: Curve> Deserialize<'de> for Point<E> {
                             }
                             point = Some(map.next_value()?)
                         }
+                        PointField::X => {
+                            if x.is_some() {
+                                return Err(A::Error::duplicate_field("x"));
+                            }
+                            x = Some(map.next_value()?)
+                        }
+                        PointField::Y => {
+                            if y.is_some() {
+                                return Err(A::Error::duplicate_field("y"));
+                            }
+                            y = Some(map.next_value()?)
+                        }
                     }
                 }
-                let _curve_name =
-                    curve_name.ok_or_else(|| A::Error::missing_field("curve_name"))?;
-                let point = point.ok_or_else(|| A::Error::missing_field("point"))?;
-                Ok(point.0)
+                match (point, x, y) {
+                    (Some(point), None, None) => {
+                        let _curve_name =
+                            curve_name.ok_or_else(|| A::Error::missing_field("curve_name"))?;
+                        Ok(point.0)
+                    }
+                    // Legacy format: coordinates encoded as hex strings
+                    (None, Some(x), Some(y)) => {
+                        let parse = |coord: &str| {
+                            BigInt::from_hex(coord)
+                                .map_err(|_| A::Error::custom("malformed hex encoding"))
+                        };
+                        Point::from_coords(&parse(&x)?, &parse(&y)?)
+                            .map_err(|e| A::Error::custom(format!("invalid point: {}", e)))
+                    }
+                    (None, None, None) => Err(A::Error::missing_field("point")),
+                    _ => Err(A::Error::custom(
+                        "point must be given either as `point` or as `x` and `y` coordinates",
+                    )),
+                }
+            }
+
+            fn visit_str<Err>(self, v: &str) -> Result<Self::Value, Err>
+            where
+                Err: Error,
+            {
+                PointFromBytes::deserialize(StrDeserializer::<Err>::new(v)).map(|p| p.0)
             }
 
             fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -101,7 +182,14 @@ impl<'de, E: Curve> Deserialize<'de> for Point<E> {
             }
         }
 
-        deserializer.deserialize_struct("Point", &["curve", "point"], PointVisitor(PhantomData))
+        if deserializer.is_human_readable() {
+            // Besides `{"curve": ..., "point": ...}` struct, human-readable formats also accept
+            // a point given as a plain hex string, or as `{"x": ..., "y": ...}` coordinates
+            // (format used by older versions of the library)
+            deserializer.deserialize_any(PointVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_struct("Point", &["curve", "point"], PointVisitor(PhantomData))
+        }
     }
 }
 
@@ -110,6 +198,8 @@ impl<'de, E: Curve> Deserialize<'de> for Point<E> {
 enum PointField {
     Curve,
     Point,
+    X,
+    Y,
 }
 
 /// Efficient guard for asserting that deserialized `&str`/`String` is `E::CURVE_NAME`
@@ -171,6 +261,9 @@ impl<'de, E: Curve> Deserialize<'de> for PointFromBytes<E> {
             where
                 Err: Error,
             {
+                let uncompressed_len = <E::Point as ECPoint>::UncompressedPointLength::USIZE;
+                let compressed_len = <E::Point as ECPoint>::CompressedPointLength::USIZE;
+                let v = strip_version_tag(v, &[compressed_len, uncompressed_len])?;
                 Point::from_bytes(v).map_err(|e| Err::custom(format!("invalid point: {}", e)))
             }
 
@@ -182,37 +275,31 @@ impl<'de, E: Curve> Deserialize<'de> for PointFromBytes<E> {
                 let seq_len_hint = seq.size_hint();
                 let uncompressed_len = <E::Point as ECPoint>::UncompressedPointLength::USIZE;
                 let compressed_len = <E::Point as ECPoint>::CompressedPointLength::USIZE;
+                let expected = || {
+                    format!(
+                        "either {} or {} bytes (including version tag)",
+                        compressed_len + 1,
+                        uncompressed_len + 1
+                    )
+                };
 
-                let mut buffer =
-                    GenericArray::<u8, <E::Point as ECPoint>::UncompressedPointLength>::default();
-                let mut seq_len = 0;
-
-                for x in buffer.iter_mut() {
-                    *x = match seq.next_element()? {
-                        Some(b) => b,
-                        None => break,
-                    };
-                    seq_len += 1;
-                }
-
-                if seq_len == uncompressed_len {
-                    // Ensure that there are no other elements in the sequence
-                    if seq.next_element::<IgnoredAny>()?.is_some() {
+                let mut buffer = Vec::with_capacity(uncompressed_len + 1);
+                while let Some(b) = seq.next_element()? {
+                    if buffer.len() == uncompressed_len + 1 {
                         return Err(A::Error::invalid_length(
-                            seq_len_hint.unwrap_or(seq_len + 1),
-                            &format!("either {} or {} bytes", compressed_len, uncompressed_len)
-                                .as_str(),
+                            seq_len_hint.unwrap_or(buffer.len() + 1),
+                            &expected().as_str(),
                         ));
                     }
-                } else if seq_len != compressed_len {
-                    return Err(A::Error::invalid_length(
-                        seq_len_hint.unwrap_or(seq_len),
-                        &format!("either {} or {} bytes", compressed_len, uncompressed_len)
-                            .as_str(),
-                    ));
+                    buffer.push(b);
+                }
+
+                let bytes = strip_version_tag(&buffer, &[compressed_len, uncompressed_len])?;
+                if bytes.len() != compressed_len && bytes.len() != uncompressed_len {
+                    return Err(A::Error::invalid_length(buffer.len(), &expected().as_str()));
                 }
 
-                Point::from_bytes(&buffer.as_slice()[..seq_len])
+                Point::from_bytes(bytes)
                     .map_err(|e| A::Error::custom(format!("invalid point: {}", e)))
             }
 
@@ -269,10 +356,14 @@ impl<E: Curve> Serialize for Scalar<E> {
         let mut s = serializer.serialize_struct("Scalar", 2)?;
         s.serialize_field("curve", E::CURVE_NAME)?;
         if !is_human_readable {
+            let bytes = self.to_bytes();
+            let mut tagged = Zeroizing::new(Vec::with_capacity(1 + bytes.len()));
+            tagged.push(BINARY_FORMAT_VERSION);
+            tagged.extend_from_slice(&bytes);
             s.serialize_field(
                 "scalar",
                 // Serializes bytes efficiently
-                Bytes::new(&self.to_bytes()),
+                Bytes::new(&tagged),
             )?;
         } else {
             s.serialize_field("scalar", &hex::encode(&*self.to_bytes()))?;
@@ -370,6 +461,8 @@ impl<'de, E: Curve> Deserialize<'de> for ScalarFromBytes<E> {
             where
                 Err: Error,
             {
+                let expected_len = <E::Scalar as ECScalar>::ScalarLength::USIZE;
+                let v = strip_version_tag(v, &[expected_len])?;
                 Scalar::from_bytes(v).map_err(|_| Err::custom("invalid scalar"))
             }
 
@@ -380,32 +473,25 @@ impl<'de, E: Curve> Deserialize<'de> for ScalarFromBytes<E> {
             {
                 let seq_len_hint = seq.size_hint();
                 let expected_len = <E::Scalar as ECScalar>::ScalarLength::USIZE;
+                let expected = || format!("{} bytes (including version tag)", expected_len + 1);
 
-                let mut buffer =
-                    GenericArray::<u8, <E::Scalar as ECScalar>::ScalarLength>::default();
-
-                for (i, x) in buffer.iter_mut().enumerate() {
-                    *x = match seq.next_element()? {
-                        Some(b) => b,
-                        None => {
-                            return Err(A::Error::invalid_length(
-                                i,
-                                &format!("{} bytes", expected_len).as_str(),
-                            ))
-                        }
-                    };
+                let mut buffer = Zeroizing::new(Vec::with_capacity(expected_len + 1));
+                while let Some(b) = seq.next_element()? {
+                    if buffer.len() == expected_len + 1 {
+                        return Err(A::Error::invalid_length(
+                            seq_len_hint.unwrap_or(buffer.len() + 1),
+                            &expected().as_str(),
+                        ));
+                    }
+                    buffer.push(b);
                 }
 
-                // Ensure that there are no other elements in the sequence
-                if seq.next_element::<IgnoredAny>()?.is_some() {
-                    return Err(A::Error::invalid_length(
-                        seq_len_hint.unwrap_or(expected_len + 1),
-                        &format!("{} bytes", expected_len).as_str(),
-                    ));
+                let bytes = strip_version_tag(&buffer, &[expected_len])?;
+                if bytes.len() != expected_len {
+                    return Err(A::Error::invalid_length(buffer.len(), &expected().as_str()));
                 }
 
-                Scalar::from_bytes(buffer.as_slice())
-                    .map_err(|_| A::Error::custom("invalid scalar"))
+                Scalar::from_bytes(bytes).map_err(|_| A::Error::custom("invalid scalar"))
             }
 
             fn visit_str<Err>(self, v: &str) -> Result<Self::Value, Err>
@@ -429,9 +515,15 @@ impl<'de, E: Curve> Deserialize<'de> for ScalarFromBytes<E> {
             }
         }
 
-        deserializer
-            .deserialize_bytes(ScalarBytesVisitor(PhantomData))
-            .map(ScalarFromBytes)
+        if !deserializer.is_human_readable() {
+            deserializer
+                .deserialize_bytes(ScalarBytesVisitor(PhantomData))
+                .map(ScalarFromBytes)
+        } else {
+            deserializer
+                .deserialize_str(ScalarBytesVisitor(PhantomData))
+                .map(ScalarFromBytes)
+        }
     }
 }
 
@@ -445,18 +537,28 @@ enum ScalarField {
 #[cfg(test)]
 mod serde_tests {
     use serde_test::{
-        assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token::*,
+        assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact, Configure, Readable,
+        Token::*,
     };
 
+    use crate::arithmetic::Converter;
     use crate::elliptic::curves::*;
     use crate::test_for_all_curves;
 
+    use super::BINARY_FORMAT_VERSION;
+
+    fn tagged(bytes: &[u8]) -> Vec<u8> {
+        let mut tagged = vec![BINARY_FORMAT_VERSION];
+        tagged.extend_from_slice(bytes);
+        tagged
+    }
+
     test_for_all_curves!(serializes_deserializes_point);
     fn serializes_deserializes_point<E: Curve>() {
         let random_point = Point::<E>::generator() * Scalar::random();
         for point in [Point::zero(), random_point] {
             println!("Point: {:?}", point);
-            let bytes = point.to_bytes(true).to_vec();
+            let bytes = tagged(&point.to_bytes(true));
             let tokens = [
                 Struct {
                     name: "Point",
@@ -476,7 +578,7 @@ mod serde_tests {
     fn serializes_deserializes_scalar<E: Curve>() {
         for scalar in [Scalar::<E>::zero(), Scalar::random()] {
             println!("Scalar: {:?}", scalar);
-            let bytes = scalar.to_bytes().to_vec();
+            let bytes = tagged(&scalar.to_bytes());
             let tokens = [
                 Struct {
                     name: "Scalar",
@@ -497,7 +599,7 @@ mod serde_tests {
         let random_point = Point::<E>::generator() * Scalar::random();
         for point in [Point::zero(), random_point] {
             println!("Point: {:?}", point);
-            let bytes = point.to_bytes(true);
+            let bytes = tagged(&point.to_bytes(true));
             let mut tokens = vec![
                 Struct {
                     name: "Point",
@@ -520,7 +622,7 @@ mod serde_tests {
     fn deserializes_scalar_from_seq_of_bytes<E: Curve>() {
         for scalar in [Scalar::<E>::zero(), Scalar::random()] {
             println!("Scalar: {:?}", scalar);
-            let bytes = scalar.to_bytes();
+            let bytes = tagged(&scalar.to_bytes());
             let mut tokens = vec![
                 Struct {
                     name: "Scalar",
@@ -547,7 +649,7 @@ mod serde_tests {
                 len: Option::Some(2),
             },
             Str(E::CURVE_NAME),
-            Bytes(point.to_bytes(true).to_vec().leak()),
+            Bytes(tagged(&point.to_bytes(true)).leak()),
             SeqEnd,
         ];
         assert_de_tokens(&point.compact(), &tokens);
@@ -561,7 +663,7 @@ mod serde_tests {
                 len: Option::Some(2),
             },
             Str(E::CURVE_NAME),
-            Bytes(scalar.to_bytes().to_vec().leak()),
+            Bytes(tagged(&scalar.to_bytes()).leak()),
             SeqEnd,
         ];
         assert_de_tokens(&scalar.compact(), &tokens);
@@ -586,6 +688,64 @@ mod serde_tests {
         assert_tokens(&point.readable(), &tokens);
     }
 
+    #[test]
+    fn serializes_secp256k1_point_as_compressed_sec1_hex() {
+        let point = Point::<Secp256k1>::generator().to_point();
+        let hex = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        assert_eq!(hex.len(), 66);
+        let tokens = [
+            Struct {
+                name: "Point",
+                len: 2,
+            },
+            Str("curve"),
+            Str("secp256k1"),
+            Str("point"),
+            Str(hex),
+            StructEnd,
+        ];
+        assert_tokens(&point.readable(), &tokens);
+    }
+
+    #[test]
+    fn deserializes_secp256k1_point_from_legacy_and_plain_hex_formats() {
+        let point = Point::<Secp256k1>::generator() * Scalar::random();
+        let coords = point.coords().unwrap();
+
+        let legacy = format!(
+            r#"{{"x":"{}","y":"{}"}}"#,
+            coords.x.to_hex(),
+            coords.y.to_hex()
+        );
+        let compressed = format!(r#""{}""#, hex::encode(&*point.to_bytes(true)));
+        let uncompressed = format!(r#""{}""#, hex::encode(&*point.to_bytes(false)));
+        let current = serde_json::to_string(&point).unwrap();
+
+        for json in [legacy, compressed, uncompressed, current] {
+            let deserialized: Point<Secp256k1> = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, point, "{}", json);
+        }
+
+        // Mixed or incomplete forms are rejected
+        let x = coords.x.to_hex();
+        for json in [
+            format!(r#"{{"x":"{}"}}"#, x),
+            format!(r#"{{"x":"{}","y":"{}"}}"#, x, x),
+            format!(
+                r#"{{"x":"{}","point":"{}"}}"#,
+                x,
+                hex::encode(&*point.to_bytes(true))
+            ),
+            r#""not hex""#.to_string(),
+        ] {
+            assert!(
+                serde_json::from_str::<Point<Secp256k1>>(&json).is_err(),
+                "{}",
+                json
+            );
+        }
+    }
+
     test_for_all_curves!(serializes_deserializes_scalar_in_human_readable_format);
     fn serializes_deserializes_scalar_in_human_readable_format<E: Curve>() {
         let scalar = Scalar::<E>::random();
@@ -603,6 +763,139 @@ mod serde_tests {
         assert_tokens(&scalar.readable(), &tokens);
     }
 
+    test_for_all_curves!(point_and_scalar_round_trip_through_json_and_bincode);
+    fn point_and_scalar_round_trip_through_json_and_bincode<E: Curve>() {
+        let scalar = Scalar::<E>::random();
+        let point = Point::generator() * &scalar;
+
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(serde_json::from_str::<Point<E>>(&json).unwrap(), point);
+        let json = serde_json::to_string(&scalar).unwrap();
+        assert_eq!(serde_json::from_str::<Scalar<E>>(&json).unwrap(), scalar);
+
+        // Binary form is the curve name followed by version tag and raw point bytes, each prefixed
+        // with its length
+        let point_bytes = point.to_bytes(true);
+        let encoded = bincode::serialize(&point).unwrap();
+        assert_eq!(
+            encoded.len(),
+            8 + E::CURVE_NAME.len() + 8 + 1 + point_bytes.len()
+        );
+        assert!(encoded.ends_with(&point_bytes));
+        assert_eq!(bincode::deserialize::<Point<E>>(&encoded).unwrap(), point);
+
+        let scalar_bytes = scalar.to_bytes();
+        let encoded = bincode::serialize(&scalar).unwrap();
+        assert_eq!(
+            encoded.len(),
+            8 + E::CURVE_NAME.len() + 8 + 1 + scalar_bytes.len()
+        );
+        assert!(encoded.ends_with(&scalar_bytes));
+        assert_eq!(bincode::deserialize::<Scalar<E>>(&encoded).unwrap(), scalar);
+    }
+
+    test_for_all_curves!(identity_from_arithmetic_round_trips_through_json_and_bincode);
+    fn identity_from_arithmetic_round_trips_through_json_and_bincode<E: Curve>() {
+        let point = Point::<E>::generator() * Scalar::random();
+        let identities = [
+            &point - &point.clone(),
+            &point * Scalar::zero(),
+            point.clone() + -&point,
+        ];
+        for identity in identities {
+            assert!(identity.is_zero());
+            assert_eq!(&identity + &point, point);
+
+            let json = serde_json::to_string(&identity).unwrap();
+            assert_eq!(serde_json::from_str::<Point<E>>(&json).unwrap(), identity);
+            let encoded = bincode::serialize(&identity).unwrap();
+            assert_eq!(
+                bincode::deserialize::<Point<E>>(&encoded).unwrap(),
+                identity
+            );
+        }
+    }
+
+    /// Binary encoding keeps the `{curve, point}` struct, so that deserialization still detects
+    /// a point or scalar of a different curve. Apart from the curve name and bincode length
+    /// prefixes, it only carries the version tag and the raw 33/32 bytes.
+    #[test]
+    fn secp256k1_binary_encoding_is_curve_name_and_tagged_raw_bytes() {
+        let scalar = Scalar::<Secp256k1>::random();
+        let point = Point::generator() * &scalar;
+        let name_len = 8 + "secp256k1".len();
+
+        let encoded = bincode::serialize(&point).unwrap();
+        assert_eq!(encoded.len(), name_len + 8 + 1 + 33);
+        assert_eq!(encoded[name_len + 8], BINARY_FORMAT_VERSION);
+        assert_eq!(&encoded[name_len + 8 + 1..], &*point.to_bytes(true));
+
+        let encoded = bincode::serialize(&scalar).unwrap();
+        assert_eq!(encoded.len(), name_len + 8 + 1 + 32);
+        assert_eq!(encoded[name_len + 8], BINARY_FORMAT_VERSION);
+        assert_eq!(&encoded[name_len + 8 + 1..], &*scalar.to_bytes());
+    }
+
+    test_for_all_curves!(doesnt_deserialize_untagged_v1_binary_encoding);
+    fn doesnt_deserialize_untagged_v1_binary_encoding<E: Curve>() {
+        let point = Point::<E>::generator() * Scalar::random();
+        let tokens = [
+            Struct {
+                name: "Point",
+                len: 2,
+            },
+            Str("curve"),
+            Str(E::CURVE_NAME),
+            Str("point"),
+            Bytes(point.to_bytes(true).to_vec().leak()),
+            StructEnd,
+        ];
+        assert_de_tokens_error::<Compact<Point<E>>>(
+            &tokens,
+            "binary format version tag is missing: data was probably serialized by curv v0.9 or \
+             older (format v1), expected format v2",
+        );
+
+        let scalar = Scalar::<E>::random();
+        let tokens = [
+            Struct {
+                name: "Scalar",
+                len: 2,
+            },
+            Str("curve"),
+            Str(E::CURVE_NAME),
+            Str("scalar"),
+            Bytes(scalar.to_bytes().to_vec().leak()),
+            StructEnd,
+        ];
+        assert_de_tokens_error::<Compact<Scalar<E>>>(
+            &tokens,
+            "binary format version tag is missing: data was probably serialized by curv v0.9 or \
+             older (format v1), expected format v2",
+        );
+    }
+
+    test_for_all_curves!(doesnt_deserialize_unknown_binary_format_version);
+    fn doesnt_deserialize_unknown_binary_format_version<E: Curve>() {
+        let mut bytes = tagged(&Scalar::<E>::random().to_bytes());
+        bytes[0] = 7;
+        let tokens = [
+            Struct {
+                name: "Scalar",
+                len: 2,
+            },
+            Str("curve"),
+            Str(E::CURVE_NAME),
+            Str("scalar"),
+            Bytes(bytes.leak()),
+            StructEnd,
+        ];
+        assert_de_tokens_error::<Compact<Scalar<E>>>(
+            &tokens,
+            "unsupported binary format version 7, expected 2",
+        );
+    }
+
     test_for_all_curves!(doesnt_deserialize_point_from_different_curve);
     fn doesnt_deserialize_point_from_different_curve<E: Curve>() {
         let tokens = [
@@ -613,13 +906,12 @@ mod serde_tests {
             Str("curve"),
             Str("%not_existing%"),
         ];
-        assert_de_tokens_error::<Point<E>>(
-            &tokens,
-            &format!(
-                "belongs to %not_existing% curve, expected {} curve",
-                E::CURVE_NAME
-            ),
-        )
+        let error = format!(
+            "belongs to %not_existing% curve, expected {} curve",
+            E::CURVE_NAME
+        );
+        assert_de_tokens_error::<Compact<Point<E>>>(&tokens, &error);
+        assert_de_tokens_error::<Readable<Point<E>>>(&tokens, &error);
     }
 
     test_for_all_curves!(doesnt_deserialize_scalar_from_different_curve);
@@ -640,4 +932,74 @@ mod serde_tests {
             ),
         )
     }
+
+    test_for_all_curves!(doesnt_deserialize_malformed_human_readable_point);
+    fn doesnt_deserialize_malformed_human_readable_point<E: Curve>() {
+        let valid = Point::<E>::generator().to_point().to_hex();
+        let non_hex = "z".repeat(valid.len());
+        let overlong = format!("{}00", valid);
+        for (point, error) in [
+            (non_hex.as_str(), "malformed hex encoding"),
+            (overlong.as_str(), "invalid point"),
+            ("", "invalid point"),
+        ] {
+            let tokens = [
+                Struct {
+                    name: "Point",
+                    len: 2,
+                },
+                Str("curve"),
+                Str(E::CURVE_NAME),
+                Str("point"),
+                Str(Box::leak(point.to_owned().into_boxed_str())),
+            ];
+            assert_de_tokens_error::<Readable<Point<E>>>(&tokens, error);
+        }
+    }
+
+    test_for_all_curves!(doesnt_deserialize_malformed_human_readable_scalar);
+    fn doesnt_deserialize_malformed_human_readable_scalar<E: Curve>() {
+        let valid = Scalar::<E>::random().to_hex();
+        let non_hex = "z".repeat(valid.len());
+        let overlong = format!("{}00", valid);
+        for (scalar, error) in [
+            (non_hex, "malformed hex encoding".to_owned()),
+            (
+                overlong,
+                format!(
+                    "invalid length {}, expected {}",
+                    valid.len() + 2,
+                    valid.len()
+                ),
+            ),
+        ] {
+            let tokens = [
+                Struct {
+                    name: "Scalar",
+                    len: 2,
+                },
+                Str("curve"),
+                Str(E::CURVE_NAME),
+                Str("scalar"),
+                Str(Box::leak(scalar.into_boxed_str())),
+            ];
+            assert_de_tokens_error::<Readable<Scalar<E>>>(&tokens, &error);
+        }
+    }
+
+    #[test]
+    fn doesnt_deserialize_out_of_range_secp256k1_scalar() {
+        let group_order = Scalar::<Secp256k1>::group_order().to_hex();
+        let tokens = [
+            Struct {
+                name: "Scalar",
+                len: 2,
+            },
+            Str("curve"),
+            Str(Secp256k1::CURVE_NAME),
+            Str("scalar"),
+            Str(Box::leak(group_order.into_boxed_str())),
+        ];
+        assert_de_tokens_error::<Readable<Scalar<Secp256k1>>>(&tokens, "invalid scalar");
+    }
 }
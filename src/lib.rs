@@ -14,13 +14,16 @@ pub mod cryptographic_primitives;
 mod marker;
 pub use marker::HashChoice;
 
-mod test_utils;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
 
 #[derive(Copy, PartialEq, Eq, Clone, Debug)]
 pub enum ErrorKey {
     InvalidPublicKey,
 }
 
+#[derive(Copy, PartialEq, Eq, Clone, Debug)]
 pub enum ErrorSS {
     VerifyShareError,
+    CommitmentsLengthMismatch,
 }
@@ -1,3 +1,67 @@
+//! Helpers for testing protocols built on top of the library
+//!
+//! Requires `test-utils` feature to be enabled. Macros defined in this module are only used by the
+//! library's own tests.
+
+use crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+/// Simulates distributed key generation of `n` parties with threshold `t`
+///
+/// Every party shares a random secret via [Feldman VSS](VerifiableSS), and every received share
+/// is validated against the dealer's commitments. Party `i` (`1 <= i <= n`) then sums up
+/// shares received from all dealers, resulting into `shares[i-1]`. Group public key is the first
+/// of [aggregated commitments](VerifiableSS::aggregate_commitments).
+///
+/// Returns `(group_secret, group_public, shares)`, where `group_secret` is the sum of all parties'
+/// secrets which no real party would ever learn. Any `t+1` shares are enough to reconstruct it.
+///
+/// ## Panics
+/// Panics if `t >= n`
+///
+/// ## Example
+/// ```rust
+/// use curv::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+/// use curv::elliptic::curves::{Point, Secp256k1};
+/// use curv::test_utils::simulate_dkg;
+///
+/// let (group_secret, group_public, shares) = simulate_dkg::<Secp256k1>(1, 3);
+/// assert_eq!(Point::generator() * &group_secret, group_public);
+/// assert_eq!(shares.len(), 3);
+/// ```
+pub fn simulate_dkg<E: Curve>(t: u16, n: u16) -> (Scalar<E>, Point<E>, Vec<Scalar<E>>) {
+    let secrets = Scalar::<E>::random_vec(usize::from(n));
+    let dealings = secrets
+        .iter()
+        .map(|secret| VerifiableSS::share(t, n, secret))
+        .collect::<Vec<_>>();
+
+    let shares = (1..=n)
+        .map(|i| {
+            dealings
+                .iter()
+                .map(|(vss, shares)| {
+                    let share = &shares[usize::from(i) - 1];
+                    vss.validate_share(share, i)
+                        .expect("honest dealer produced invalid share");
+                    share
+                })
+                .sum()
+        })
+        .collect();
+
+    let commitments = dealings
+        .iter()
+        .map(|(vss, _)| vss.commitments.clone())
+        .collect::<Vec<_>>();
+    let group_public = VerifiableSS::aggregate_commitments(&commitments)
+        .expect("all dealers use the same threshold")
+        .swap_remove(0);
+    let group_secret = secrets.iter().sum();
+
+    (group_secret, group_public, shares)
+}
+
 #[cfg(test)]
 #[macro_export]
 macro_rules! test_for_all_curves {
@@ -95,3 +159,21 @@ macro_rules! test_for_all_curves_and_hashes {
         crate::test_for_all_curves_and_hashes!(private: [$($attrs)*] $fn => $($rest)*);
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cryptographic_primitives::secret_sharing::shamir;
+
+    crate::test_for_all_curves!(simulated_dkg_secret_matches_group_public_key);
+    fn simulated_dkg_secret_matches_group_public_key<E: Curve>() {
+        let (group_secret, group_public, shares) = simulate_dkg::<E>(2, 5);
+        assert_eq!(shares.len(), 5);
+        assert_eq!(Point::generator() * &group_secret, group_public);
+
+        let subset = [0, 2, 4].map(|i| (i as u16 + 1, shares[i].clone()));
+        let reconstructed = shamir::reconstruct(2, &subset).unwrap();
+        assert_eq!(reconstructed, group_secret);
+        assert_eq!(Point::generator() * reconstructed, group_public);
+    }
+}